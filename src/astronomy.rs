@@ -1,10 +1,10 @@
-//! 春分・秋分の天文計算。
+//! 春分・秋分（および二十四節気・朔）の天文計算。
 //!
 //! Jean Meeus 『Astronomical Algorithms』(2nd ed.) に基づき、太陽の黄経を求めて
-//! Newton 法で分点の瞬刻を計算します。標準ライブラリのみで完結します。
+//! Newton 法で分点・節気の瞬刻を計算します。旧暦のための朔（新月）の瞬刻も求めます。標準ライブラリのみで完結します。
 //!
 //! - 精度: 1948〜3000 年で ±1 日
-//! - 対応範囲: 1948 年以降（1948 年より前は 0 を返す）
+//! - 対応範囲: 1948 年以降（1948 年より前と、計算が破綻する遠い将来の年は 0 を返す）
 
 use crate::date::Date;
 
@@ -14,6 +14,8 @@ const J2000: f64 = 2_451_545.0;
 const DAYS_PER_JULIAN_CENTURY: f64 = 36_525.0;
 /// 度からラジアンへの換算係数。
 const DEGREES_TO_RADIANS: f64 = std::f64::consts::PI / 180.0;
+/// 日本標準時 (UTC+9) の UTC からのずれ（日）。
+const JST_OFFSET_DAYS: f64 = 9.0 / 24.0;
 
 /// ユリウス日 → 日時の分解結果。
 ///
@@ -134,7 +136,11 @@ pub fn solar_ecliptic_longitude_rate(jd: f64, dt: f64) -> f64 {
 }
 
 /// 太陽が指定黄経に達する瞬刻のユリウス日を Newton 法で求めます。
-fn find_equinox_solstice_jd(
+///
+/// `initial_month`/`initial_day`（`year` 年、UTC 正午）を初期値とするため、目的の瞬刻の
+/// 数日以内の日付を与えてください。ΔT は差し引かないため、世界時の瞬刻が必要な場合は
+/// [`universal_time`] を通してください（祝日の春分日・秋分日はこの値のまま判定します）。
+pub fn find_solar_longitude_jd(
     year: i64,
     target_longitude: f64,
     initial_month: i64,
@@ -166,6 +172,123 @@ fn find_equinox_solstice_jd(
     jd
 }
 
+/// 力学時のユリウス日 `jde` から ΔT（[`delta_t_seconds`]）を差し引き、世界時のユリウス日を返します。
+pub fn universal_time(jde: f64) -> f64 {
+    let year = 2000.0 + (jde - J2000) / 365.25;
+    jde - delta_t_seconds(year) / 86_400.0
+}
+
+/// 朔望月の平均日数。
+pub const SYNODIC_MONTH: f64 = 29.530_588_861;
+
+/// 力学時と世界時の差 ΔT（秒）の近似値を返します（Espenak & Meeus の多項式）。
+pub fn delta_t_seconds(year: f64) -> f64 {
+    let y = year;
+    if (1900.0..1920.0).contains(&y) {
+        let t = y - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if (1920.0..1941.0).contains(&y) {
+        let t = y - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if (1941.0..1961.0).contains(&y) {
+        let t = y - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if (1961.0..1986.0).contains(&y) {
+        let t = y - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if (1986.0..2005.0).contains(&y) {
+        let t = y - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if (2005.0..2050.0).contains(&y) {
+        let t = y - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+    } else if (2050.0..2150.0).contains(&y) {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y)
+    } else {
+        // 範囲外は長期の放物線近似。
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    }
+}
+
+/// `k` 番目の朔（2000-01-06 の朔を 0 とする）の瞬刻を力学時のユリウス日で返します（Meeus 第 49 章）。
+pub fn new_moon_jde(k: f64) -> f64 {
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let jde = 2_451_550.097_66 + SYNODIC_MONTH * k + 0.00015437 * t2 - 0.000000150 * t3
+        + 0.00000000073 * t4;
+
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+    let rad = |deg: f64| normalize_angle(deg) * DEGREES_TO_RADIANS;
+    // 太陽の平均近点角・月の平均近点角・月の緯度引数・昇交点黄経。
+    let m = rad(2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3);
+    let mp = rad(201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4);
+    let f = rad(160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4);
+    let omega = rad(124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3);
+
+    let periodic = -0.40720 * mp.sin()
+        + 0.17241 * e * m.sin()
+        + 0.01608 * (2.0 * mp).sin()
+        + 0.01039 * (2.0 * f).sin()
+        + 0.00739 * e * (mp - m).sin()
+        - 0.00514 * e * (mp + m).sin()
+        + 0.00208 * e * e * (2.0 * m).sin()
+        - 0.00111 * (mp - 2.0 * f).sin()
+        - 0.00057 * (mp + 2.0 * f).sin()
+        + 0.00056 * e * (2.0 * mp + m).sin()
+        - 0.00042 * (3.0 * mp).sin()
+        + 0.00042 * e * (m + 2.0 * f).sin()
+        + 0.00038 * e * (m - 2.0 * f).sin()
+        - 0.00024 * e * (2.0 * mp - m).sin()
+        - 0.00017 * omega.sin()
+        - 0.00007 * (mp + 2.0 * m).sin()
+        + 0.00004 * (2.0 * mp - 2.0 * f).sin()
+        + 0.00004 * (3.0 * m).sin()
+        + 0.00003 * (mp + m - 2.0 * f).sin()
+        + 0.00003 * (2.0 * mp + 2.0 * f).sin()
+        - 0.00003 * (mp + m + 2.0 * f).sin()
+        + 0.00003 * (mp - m + 2.0 * f).sin()
+        - 0.00002 * (mp - m - 2.0 * f).sin()
+        - 0.00002 * (3.0 * mp + m).sin()
+        + 0.00002 * (4.0 * mp).sin();
+
+    // 惑星による補正項。
+    let additional: [(f64, f64, f64); 14] = [
+        (0.000325, 299.77, 0.107408),
+        (0.000165, 251.88, 0.016321),
+        (0.000164, 251.83, 26.651886),
+        (0.000126, 349.42, 36.412478),
+        (0.000110, 84.66, 18.206239),
+        (0.000062, 141.74, 53.303771),
+        (0.000060, 207.14, 2.453732),
+        (0.000056, 154.84, 7.306860),
+        (0.000047, 34.52, 27.261239),
+        (0.000042, 207.19, 0.121824),
+        (0.000040, 291.34, 1.844379),
+        (0.000037, 161.72, 24.198154),
+        (0.000035, 239.56, 25.513099),
+        (0.000023, 331.55, 3.592518),
+    ];
+    let planetary: f64 = additional
+        .iter()
+        .enumerate()
+        .map(|(i, &(coefficient, base, rate))| {
+            // A1 のみ T² の項を持つ。
+            let correction = if i == 0 { -0.009173 * t2 } else { 0.0 };
+            coefficient * rad(base + rate * k + correction).sin()
+        })
+        .sum();
+
+    jde + periodic + planetary
+}
+
 /// ユリウス日を日時へ変換します（Meeus 第 7 章）。返す日時は UTC。
 pub fn julian_day_to_datetime(jd: f64) -> JulianDateTime {
     let jd = jd + 0.5;
@@ -210,23 +333,30 @@ pub fn julian_day_to_datetime(jd: f64) -> JulianDateTime {
     }
 }
 
+/// ユリウス日を日本標準時 (UTC+9) の日時へ変換します。
+pub fn julian_day_to_jst(jd: f64) -> JulianDateTime {
+    julian_day_to_datetime(jd + JST_OFFSET_DAYS)
+}
+
 /// UTC の分点日時を日本標準時 (UTC+9) に直したときの「日」を返します。
 ///
 /// 9 時間加算で時が 24 を超えた場合のみ翌日へ繰り上げます（分・秒は繰り上げに影響しない）。
+/// 浮動小数点の精度が尽きる遠い将来の年で、求めた日付が暦日として不正か `expected_month` 以外の
+/// 月になった場合は 0 を返します。
 fn jst_day(utc: JulianDateTime, expected_month: u32) -> u32 {
-    let base = Date::new(utc.year, utc.month, utc.day)
-        .expect("equinox calculation produced an invalid calendar date");
+    let Ok(base) = Date::new(utc.year, utc.month, utc.day) else {
+        return 0;
+    };
     let jst = if utc.hour + 9 >= 24 {
         base.succ()
     } else {
         base
     };
-    debug_assert_eq!(
-        jst.month(),
-        expected_month,
-        "equinox resolved to an unexpected month"
-    );
-    jst.day()
+    if jst.month() == expected_month {
+        jst.day()
+    } else {
+        0
+    }
 }
 
 /// 春分の日（3 月）の日を返します。1948 年より前は 0。
@@ -234,7 +364,7 @@ pub fn calculate_vernal_equinox(year: i32) -> u32 {
     if year < 1948 {
         return 0;
     }
-    let jd = find_equinox_solstice_jd(year as i64, 0.0, 3, 20);
+    let jd = find_solar_longitude_jd(year as i64, 0.0, 3, 20);
     jst_day(julian_day_to_datetime(jd), 3)
 }
 
@@ -243,7 +373,7 @@ pub fn calculate_autumn_equinox(year: i32) -> u32 {
     if year < 1948 {
         return 0;
     }
-    let jd = find_equinox_solstice_jd(year as i64, 180.0, 9, 23);
+    let jd = find_solar_longitude_jd(year as i64, 180.0, 9, 23);
     jst_day(julian_day_to_datetime(jd), 9)
 }
 
@@ -272,6 +402,12 @@ mod tests {
         assert!((normalize_angle(450.0) - 90.0).abs() < 1e-9);
     }
 
+    #[test]
+    fn new_moon_example() {
+        // Meeus 例 49.a: 1977 年 2 月の朔は JDE 2443192.65118。
+        assert!((new_moon_jde(-283.0) - 2_443_192.651_18).abs() < 1e-4);
+    }
+
     #[test]
     fn equinox_known_values() {
         assert_eq!(calculate_vernal_equinox(2000), 20);
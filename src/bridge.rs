@@ -0,0 +1,87 @@
+//! 飛び石連休の休暇取得案（有給休暇の配置）の計算。
+//!
+//! 営業日に休暇を取ることで前後の休日とつながる連休の長さを求め、長い順に並べます。
+//! 営業日判定は呼び出し側が渡すため、週末の定義や登録済みの独自祝日がそのまま反映されます。
+
+use crate::business::{self, MAX_CONSECUTIVE_CLOSED_DAYS};
+use crate::date::Date;
+
+/// 休暇の取得案。`leave_days` に休暇を取ると `start`〜`end` が連休になります。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BridgeSuggestion {
+    /// 休暇を取る営業日（昇順）。
+    pub leave_days: Vec<Date>,
+    /// 連休の初日。
+    pub start: Date,
+    /// 連休の最終日。
+    pub end: Date,
+}
+
+impl BridgeSuggestion {
+    /// 連休の日数（初日と最終日を含む）を返します。
+    pub fn days(&self) -> i64 {
+        self.end.to_ordinal() - self.start.to_ordinal() + 1
+    }
+
+    /// 休暇以外の休日（週末・祝日）の日数を返します。
+    pub fn days_off_gained(&self) -> i64 {
+        self.days() - self.leave_days.len() as i64
+    }
+}
+
+/// `start`〜`end`（両端を含む）の営業日から連続する `leave_days` 日を選ぶ取得案を、
+/// 連休が長い順（同じ長さなら早い順）に返します。
+///
+/// 休暇を取る日は範囲内に限りますが、連休は範囲外の休日まで延ばした長さで数えます。
+/// 独自チェッカーによって休日が際限なく続く場合、範囲外へ延ばすのは
+/// [`MAX_CONSECUTIVE_CLOSED_DAYS`] 日までです。
+/// `leave_days` が 0 の場合や、範囲内の営業日が足りない場合は空を返します。
+pub(crate) fn bridge_suggestions(
+    start: Date,
+    end: Date,
+    leave_days: u32,
+    is_business_day: impl Fn(Date) -> bool,
+) -> Vec<BridgeSuggestion> {
+    let len = leave_days as usize;
+    if len == 0 {
+        return Vec::new();
+    }
+
+    let mut workdays = Vec::new();
+    let mut date = start;
+    while date <= end {
+        if is_business_day(date) {
+            workdays.push(date);
+        }
+        date = date.succ();
+    }
+
+    let limit = i64::from(MAX_CONSECUTIVE_CLOSED_DAYS);
+    // 休暇は連続する営業日に取る（間に営業日を挟むと連休がつながらない）ため、
+    // 営業日の並びの窓ごとに、前後の営業日の間までを連休とする。
+    let mut out: Vec<BridgeSuggestion> = workdays
+        .windows(len)
+        .enumerate()
+        .map(|(i, window)| {
+            let first = window[0];
+            let last = window[len - 1];
+            let start = match i.checked_sub(1) {
+                Some(prev) => workdays[prev].succ(),
+                None => business::previous_business_day(first, &is_business_day)
+                    .map_or(first.add_days(-limit), Date::succ),
+            };
+            let end = match workdays.get(i + len) {
+                Some(next) => next.pred(),
+                None => business::next_business_day(last, &is_business_day)
+                    .map_or(last.add_days(limit), Date::pred),
+            };
+            BridgeSuggestion {
+                leave_days: window.to_vec(),
+                start,
+                end,
+            }
+        })
+        .collect();
+    out.sort_by(|a, b| b.days().cmp(&a.days()).then(a.start.cmp(&b.start)));
+    out
+}
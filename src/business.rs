@@ -0,0 +1,256 @@
+//! 営業日計算。
+//!
+//! 週末（休業する曜日）の定義 [`Weekend`] と、任意の「営業日判定」に対する翌営業日・前営業日・
+//! 営業日加算・営業日数・休業日の調整（[`RollConvention`]）の計算を提供します。祝日判定は呼び出し側（[`crate::JPHoliday`] 等）が
+//! 渡すため、独自祝日を含めた営業日計算をチェッカーのレジストリに依存せず共通化できます。
+
+use crate::date::{Date, days_in_month};
+use crate::weekday::Weekday;
+
+/// 営業日を探す際に連続して休業日が続いてよい最大の日数（約 10 年）。
+///
+/// 独自チェッカーが全日を休日と判定する場合に探索が終わらなくなるのを防ぎます。
+pub(crate) const MAX_CONSECUTIVE_CLOSED_DAYS: u32 = 3660;
+
+/// 週末（休業する曜日）の定義。既定は土曜日・日曜日。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, Weekend};
+///
+/// // 小売業などの日曜日のみ休業。
+/// let weekend = Weekend::SUNDAY;
+/// assert!(!weekend.contains(Date::new(2024, 1, 6).unwrap())); // 土曜日
+/// assert!(weekend.contains(Date::new(2024, 1, 7).unwrap())); // 日曜日
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Weekend {
+    /// ISO 曜日 `n`（月曜=1 〜 日曜=7）をビット `n - 1` で表す集合。
+    mask: u8,
+}
+
+impl Weekend {
+    /// 土曜日・日曜日。
+    pub const SATURDAY_SUNDAY: Weekend = Weekend {
+        mask: 1 << 5 | 1 << 6,
+    };
+    /// 日曜日のみ。
+    pub const SUNDAY: Weekend = Weekend { mask: 1 << 6 };
+    /// 週末なし（祝日のみ休業）。
+    pub const NONE: Weekend = Weekend { mask: 0 };
+
+    /// ISO 曜日（月曜=1 〜 日曜=7）の並びから週末を生成します。
+    ///
+    /// 範囲外の曜日を含む場合や、全曜日が週末になる（営業日が存在しない）場合は `None`。
+    pub fn from_iso_weekdays(iso_weekdays: &[u32]) -> Option<Self> {
+        let mut mask = 0u8;
+        for &w in iso_weekdays {
+            if !(1..=7).contains(&w) {
+                return None;
+            }
+            mask |= 1 << (w - 1);
+        }
+        if mask == 0x7f {
+            return None;
+        }
+        Some(Weekend { mask })
+    }
+
+    /// 曜日の並びから週末を生成します。全曜日が週末になる場合は `None`。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Weekday, Weekend};
+    ///
+    /// let weekend = Weekend::from_weekdays(&[Weekday::Friday, Weekday::Saturday]).unwrap();
+    /// assert!(weekend.contains_weekday(Weekday::Friday));
+    /// assert!(!weekend.contains_weekday(Weekday::Sunday));
+    /// ```
+    pub fn from_weekdays(weekdays: &[Weekday]) -> Option<Self> {
+        let iso: Vec<u32> = weekdays.iter().map(|w| w.iso_number()).collect();
+        Weekend::from_iso_weekdays(&iso)
+    }
+
+    /// 曜日が週末かどうかを返します。
+    pub fn contains_weekday(self, weekday: Weekday) -> bool {
+        self.contains_iso_weekday(weekday.iso_number())
+    }
+
+    /// ISO 曜日（月曜=1 〜 日曜=7）が週末かどうかを返します。
+    pub fn contains_iso_weekday(self, iso_weekday: u32) -> bool {
+        (1..=7).contains(&iso_weekday) && self.mask & (1 << (iso_weekday - 1)) != 0
+    }
+
+    /// 指定日が週末かどうかを返します。
+    pub fn contains(self, date: Date) -> bool {
+        self.contains_weekday(date.day_of_week())
+    }
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Weekend::SATURDAY_SUNDAY
+    }
+}
+
+/// 休業日に当たった日付の調整方法（支払日・決済日の慣行）。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, RollConvention};
+///
+/// // 毎月 25 日払い、休業日なら前営業日。2024-05-25 は土曜日。
+/// assert_eq!(
+///     jpholiday::adjust(Date::new(2024, 5, 25).unwrap(), RollConvention::Preceding),
+///     Date::new(2024, 5, 24).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollConvention {
+    /// 翌営業日（Following）。
+    Following,
+    /// 翌営業日。ただし月をまたぐ場合は前営業日（Modified Following）。
+    ModifiedFollowing,
+    /// 前営業日（Preceding）。
+    Preceding,
+    /// 前営業日。ただし月をまたぐ場合は翌営業日（Modified Preceding）。
+    ModifiedPreceding,
+    /// その月の最終営業日（End of Month）。営業日かどうかにかかわらず月末営業日へ移動します。
+    EndOfMonth,
+}
+
+/// `date` を `convention` に従って営業日へ調整します。
+///
+/// [`RollConvention::EndOfMonth`] 以外では、`date` が営業日ならそのまま返します。
+/// 移動先の営業日が見つからない場合（[`find_business_day`] を参照）は `None`。
+pub(crate) fn adjust(
+    date: Date,
+    convention: RollConvention,
+    is_business_day: impl Fn(Date) -> bool,
+) -> Option<Date> {
+    let following = |d: Date| {
+        if is_business_day(d) {
+            Some(d)
+        } else {
+            next_business_day(d, &is_business_day)
+        }
+    };
+    let preceding = |d: Date| {
+        if is_business_day(d) {
+            Some(d)
+        } else {
+            previous_business_day(d, &is_business_day)
+        }
+    };
+
+    match convention {
+        RollConvention::Following => following(date),
+        RollConvention::Preceding => preceding(date),
+        RollConvention::ModifiedFollowing => match following(date) {
+            Some(rolled) if rolled.month() == date.month() => Some(rolled),
+            _ => preceding(date),
+        },
+        RollConvention::ModifiedPreceding => match preceding(date) {
+            Some(rolled) if rolled.month() == date.month() => Some(rolled),
+            _ => following(date),
+        },
+        RollConvention::EndOfMonth => {
+            let dim = days_in_month(date.year(), date.month())
+                .expect("a valid date always has a valid month");
+            let month_end = Date::new(date.year(), date.month(), dim)
+                .expect("the last day of a month is always a valid date");
+            preceding(month_end)
+        }
+    }
+}
+
+/// `date` より後の最初の営業日を返します。見つからなければ `None`（[`find_business_day`] を参照）。
+pub(crate) fn next_business_day(
+    date: Date,
+    is_business_day: impl Fn(Date) -> bool,
+) -> Option<Date> {
+    find_business_day(date, Date::succ, is_business_day)
+}
+
+/// `date` より前の最後の営業日を返します。見つからなければ `None`（[`find_business_day`] を参照）。
+pub(crate) fn previous_business_day(
+    date: Date,
+    is_business_day: impl Fn(Date) -> bool,
+) -> Option<Date> {
+    find_business_day(date, Date::pred, is_business_day)
+}
+
+/// `date` から `step` で 1 日ずつ進め、最初の営業日を返します。
+///
+/// 休業日が [`MAX_CONSECUTIVE_CLOSED_DAYS`] 日を超えて続く場合（独自チェッカーが全日を
+/// 休日と判定する場合など）は `None`。
+fn find_business_day(
+    date: Date,
+    step: fn(Date) -> Date,
+    is_business_day: impl Fn(Date) -> bool,
+) -> Option<Date> {
+    let mut current = step(date);
+    for _ in 0..MAX_CONSECUTIVE_CLOSED_DAYS {
+        if is_business_day(current) {
+            return Some(current);
+        }
+        current = step(current);
+    }
+    None
+}
+
+/// `date` から `days` 営業日後（負なら前）の日付を返します。`days` が 0 なら `date` のまま。
+///
+/// 途中で営業日が見つからなくなった場合は `None`。
+pub(crate) fn add_business_days(
+    date: Date,
+    days: i64,
+    is_business_day: impl Fn(Date) -> bool,
+) -> Option<Date> {
+    let mut current = date;
+    for _ in 0..days.unsigned_abs() {
+        current = if days > 0 {
+            next_business_day(current, &is_business_day)?
+        } else {
+            previous_business_day(current, &is_business_day)?
+        };
+    }
+    Some(current)
+}
+
+/// 営業日の探索結果を取り出します。公開 API のうち `Date` を返すものが使います。
+///
+/// # Panics
+/// `found` が `None`（休業日が [`MAX_CONSECUTIVE_CLOSED_DAYS`] 日を超えて続いた）の場合。
+pub(crate) fn expect_business_day(found: Option<Date>, date: Date) -> Date {
+    found.unwrap_or_else(|| {
+        panic!(
+            "no business day within {MAX_CONSECUTIVE_CLOSED_DAYS} days of {date}; \
+             a registered OriginalHolidayChecker may mark every day as a holiday"
+        )
+    })
+}
+
+/// `start` の翌日から `end` まで（両端のうち `end` のみ含む）の営業日数を返します。
+///
+/// `end < start` の場合は `end` の翌日から `start` までの営業日数を負数で返します。
+pub(crate) fn business_days_between(
+    start: Date,
+    end: Date,
+    is_business_day: impl Fn(Date) -> bool,
+) -> i64 {
+    let (from, to, sign) = if start <= end {
+        (start, end, 1)
+    } else {
+        (end, start, -1)
+    };
+    let mut count = 0;
+    let mut current = from.succ();
+    while current <= to {
+        if is_business_day(current) {
+            count += 1;
+        }
+        current = current.succ();
+    }
+    count * sign
+}
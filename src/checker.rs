@@ -3,8 +3,11 @@
 //! 組込みの祝日（祝日法の年代別ロジック）、振替休日、国民の休日の判定を行います。
 
 use crate::astronomy;
-use crate::date::{Date, days_in_month};
-use crate::model::Holiday;
+use crate::date::Date;
+use crate::locale::{self, Locale};
+use crate::model::{BuiltinHoliday, Holiday, HolidayKind};
+use crate::registry::HolidayCheckerRegistry;
+use crate::weekday::Weekday;
 use std::any::TypeId;
 use std::sync::Arc;
 
@@ -13,13 +16,17 @@ use std::sync::Arc;
 /// この日より前には国民の祝日は法的に存在しないため、組込みの祝日（および振替休日・国民の休日）は
 /// すべて施行日以降のみ有効とする。祝日法に忠実に施行日で区切り、施行日より前の年・日付には
 /// 国民の祝日を返さない（利用者の独自祝日には影響しない）。
-const NATIONAL_HOLIDAY_ACT_ENFORCEMENT: (i32, u32, u32) = (1948, 7, 20);
+pub(crate) const NATIONAL_HOLIDAY_ACT_ENFORCEMENT: (i32, u32, u32) = (1948, 7, 20);
 
 /// 利用者が独自の祝日を定義するためのトレイト。
 ///
 /// 実装した型を [`crate::JPHoliday::register`] や [`crate::register`] で登録すると、
 /// 判定に加わります。
 ///
+/// 営業日の計算（[`crate::JPHoliday::next_business_day`] など）は、休日が約 10 年
+/// （3660 日）を超えて連続しないことを前提とします。すべての日を休日と判定するチェッカーを
+/// 登録すると、これらの計算は panic します。
+///
 /// # Examples
 /// ```
 /// use jpholiday::{Date, OriginalHolidayChecker};
@@ -39,6 +46,13 @@ pub trait OriginalHolidayChecker: Send + Sync {
     fn is_holiday(&self, date: Date) -> bool;
     /// 指定日の休日名を返します。
     fn holiday_name(&self, date: Date) -> String;
+    /// 指定日の休日名を指定言語で返します。
+    ///
+    /// 既定の実装は言語によらず [`OriginalHolidayChecker::holiday_name`] を返します。
+    fn localized_holiday_name(&self, date: Date, locale: Locale) -> String {
+        let _ = locale;
+        self.holiday_name(date)
+    }
 }
 
 /// 登録済みの独自チェッカー 1 件。重複登録・登録解除を型単位で扱うため `TypeId` を保持します。
@@ -51,24 +65,9 @@ pub(crate) struct OriginalEntry {
     pub(crate) checker: Arc<dyn OriginalHolidayChecker>,
 }
 
-/// 「その月の第 `nth` 週の ISO 曜日 `iso_weekday`」に当たる日を返します。
-pub(crate) fn nth_weekday_day(year: i32, month: u32, nth: u32, iso_weekday: u32) -> Option<u32> {
-    if !(1..=5).contains(&nth) || !(1..=7).contains(&iso_weekday) {
-        return None;
-    }
-    let dim = days_in_month(year, month)?;
-    let mut count = 0;
-    for day in 1..=dim {
-        if let Ok(date) = Date::new(year, month, day)
-            && date.iso_weekday() == iso_weekday
-        {
-            count += 1;
-            if count == nth {
-                return Some(day);
-            }
-        }
-    }
-    None
+/// 「その月の第 `nth` 週の `weekday`」に当たる日を返します。
+pub(crate) fn nth_weekday_day(year: i32, month: u32, nth: u32, weekday: Weekday) -> Option<u32> {
+    Date::nth_weekday(year, month, nth, weekday).map(Date::day)
 }
 
 /// 組込みの祝日種別。
@@ -165,7 +164,7 @@ impl Builtin {
 
             Builtin::AdultDay => {
                 (y <= 1999 && m == 1 && d == 15)
-                    || (y >= 2000 && m == 1 && nth_weekday_day(y, 1, 2, 1) == Some(d))
+                    || (y >= 2000 && m == 1 && nth_weekday_day(y, 1, 2, Weekday::Monday) == Some(d))
             }
 
             Builtin::FoundationDay => y >= 1967 && m == 2 && d == 11,
@@ -198,7 +197,7 @@ impl Builtin {
                     return m == 7 && d == 22;
                 }
                 ((1996..=2002).contains(&y) && m == 7 && d == 20)
-                    || (y >= 2003 && m == 7 && nth_weekday_day(y, 7, 3, 1) == Some(d))
+                    || (y >= 2003 && m == 7 && nth_weekday_day(y, 7, 3, Weekday::Monday) == Some(d))
             }
 
             Builtin::MountainDay => {
@@ -215,7 +214,7 @@ impl Builtin {
 
             Builtin::RespectForTheAgedDay => {
                 ((1966..=2002).contains(&y) && m == 9 && d == 15)
-                    || (y >= 2003 && m == 9 && nth_weekday_day(y, 9, 3, 1) == Some(d))
+                    || (y >= 2003 && m == 9 && nth_weekday_day(y, 9, 3, Weekday::Monday) == Some(d))
             }
 
             Builtin::AutumnEquinox => m == 9 && d == astronomy::calculate_autumn_equinox(y),
@@ -224,7 +223,7 @@ impl Builtin {
                 ((1966..=1999).contains(&y) && m == 10 && d == 10)
                     || ((2000..=2019).contains(&y)
                         && m == 10
-                        && nth_weekday_day(y, 10, 2, 1) == Some(d))
+                        && nth_weekday_day(y, 10, 2, Weekday::Monday) == Some(d))
             }
 
             Builtin::SportsDay => {
@@ -236,7 +235,7 @@ impl Builtin {
                 if y == 2021 {
                     return m == 7 && d == 23;
                 }
-                y >= 2020 && m == 10 && nth_weekday_day(y, 10, 2, 1) == Some(d)
+                y >= 2020 && m == 10 && nth_weekday_day(y, 10, 2, Weekday::Monday) == Some(d)
             }
 
             Builtin::CultureDay => m == 11 && d == 3,
@@ -252,8 +251,57 @@ impl Builtin {
         }
     }
 
+    /// その年にこの祝日に当たる日付を、年内を走査せずに直接求めます（該当しなければ `None`）。
+    ///
+    /// 年代別の規則から候補日を求めたうえで [`Builtin::is_holiday`] で確認するため、
+    /// 施行日境界や廃止・新設年も含めて [`Builtin::is_holiday`] と常に一致します。
+    pub(crate) fn date_in(self, year: i32) -> Option<Date> {
+        let y = year;
+        let monday = |month: u32, nth: u32| nth_weekday_day(y, month, nth, Weekday::Monday);
+        let (m, d) = match self {
+            Builtin::NewYear => (1, Some(1)),
+            Builtin::AdultDay if y <= 1999 => (1, Some(15)),
+            Builtin::AdultDay => (1, monday(1, 2)),
+            Builtin::FoundationDay => (2, Some(11)),
+            Builtin::EmperorsBirthday if y <= 1988 => (4, Some(29)),
+            Builtin::EmperorsBirthday if y <= 2018 => (12, Some(23)),
+            Builtin::EmperorsBirthday => (2, Some(23)),
+            Builtin::VernalEquinox => (3, Some(astronomy::calculate_vernal_equinox(y))),
+            Builtin::GreeneryDay if y <= 2006 => (4, Some(29)),
+            Builtin::GreeneryDay => (5, Some(4)),
+            Builtin::ShowaDay => (4, Some(29)),
+            Builtin::ConstitutionMemorialDay => (5, Some(3)),
+            Builtin::ChildrensDay => (5, Some(5)),
+            Builtin::SeaDay if y == 2020 => (7, Some(23)),
+            Builtin::SeaDay if y == 2021 => (7, Some(22)),
+            Builtin::SeaDay if y <= 2002 => (7, Some(20)),
+            Builtin::SeaDay => (7, monday(7, 3)),
+            Builtin::MountainDay if y == 2020 => (8, Some(10)),
+            Builtin::MountainDay if y == 2021 => (8, Some(8)),
+            Builtin::MountainDay => (8, Some(11)),
+            Builtin::RespectForTheAgedDay if y <= 2002 => (9, Some(15)),
+            Builtin::RespectForTheAgedDay => (9, monday(9, 3)),
+            Builtin::AutumnEquinox => (9, Some(astronomy::calculate_autumn_equinox(y))),
+            Builtin::HealthAndSportsDay if y <= 1999 => (10, Some(10)),
+            Builtin::HealthAndSportsDay => (10, monday(10, 2)),
+            Builtin::SportsDay if y == 2020 => (7, Some(24)),
+            Builtin::SportsDay if y == 2021 => (7, Some(23)),
+            Builtin::SportsDay => (10, monday(10, 2)),
+            Builtin::CultureDay => (11, Some(3)),
+            Builtin::LaborThanksgivingDay => (11, Some(23)),
+            Builtin::ExtraHoliday1959 => (4, Some(10)),
+            Builtin::ExtraHoliday1989 => (2, Some(24)),
+            Builtin::ExtraHoliday1990 => (11, Some(12)),
+            Builtin::ExtraHoliday1993 => (6, Some(9)),
+            Builtin::ExtraHoliday2019May => (5, Some(1)),
+            Builtin::ExtraHoliday2019Oct => (10, Some(22)),
+        };
+        let date = Date::new(y, m, d?).ok()?;
+        self.is_holiday(date).then_some(date)
+    }
+
     /// この祝日の名称を返します。
-    pub(crate) fn name(self) -> &'static str {
+    pub(crate) const fn name(self) -> &'static str {
         match self {
             Builtin::NewYear => "元日",
             Builtin::AdultDay => "成人の日",
@@ -280,6 +328,71 @@ impl Builtin {
             Builtin::ExtraHoliday2019Oct => "即位礼正殿の儀",
         }
     }
+
+    /// 公開の [`BuiltinHoliday`] から変換します（定数式で使うため `From` とは別に定義する）。
+    pub(crate) const fn from_public(b: BuiltinHoliday) -> Self {
+        match b {
+            BuiltinHoliday::NewYear => Builtin::NewYear,
+            BuiltinHoliday::AdultDay => Builtin::AdultDay,
+            BuiltinHoliday::FoundationDay => Builtin::FoundationDay,
+            BuiltinHoliday::EmperorsBirthday => Builtin::EmperorsBirthday,
+            BuiltinHoliday::VernalEquinox => Builtin::VernalEquinox,
+            BuiltinHoliday::GreeneryDay => Builtin::GreeneryDay,
+            BuiltinHoliday::ShowaDay => Builtin::ShowaDay,
+            BuiltinHoliday::ConstitutionMemorialDay => Builtin::ConstitutionMemorialDay,
+            BuiltinHoliday::ChildrensDay => Builtin::ChildrensDay,
+            BuiltinHoliday::SeaDay => Builtin::SeaDay,
+            BuiltinHoliday::MountainDay => Builtin::MountainDay,
+            BuiltinHoliday::RespectForTheAgedDay => Builtin::RespectForTheAgedDay,
+            BuiltinHoliday::AutumnEquinox => Builtin::AutumnEquinox,
+            BuiltinHoliday::HealthAndSportsDay => Builtin::HealthAndSportsDay,
+            BuiltinHoliday::SportsDay => Builtin::SportsDay,
+            BuiltinHoliday::CultureDay => Builtin::CultureDay,
+            BuiltinHoliday::LaborThanksgivingDay => Builtin::LaborThanksgivingDay,
+            BuiltinHoliday::ExtraHoliday1959 => Builtin::ExtraHoliday1959,
+            BuiltinHoliday::ExtraHoliday1989 => Builtin::ExtraHoliday1989,
+            BuiltinHoliday::ExtraHoliday1990 => Builtin::ExtraHoliday1990,
+            BuiltinHoliday::ExtraHoliday1993 => Builtin::ExtraHoliday1993,
+            BuiltinHoliday::ExtraHoliday2019May => Builtin::ExtraHoliday2019May,
+            BuiltinHoliday::ExtraHoliday2019Oct => Builtin::ExtraHoliday2019Oct,
+        }
+    }
+}
+
+impl From<Builtin> for BuiltinHoliday {
+    fn from(b: Builtin) -> Self {
+        match b {
+            Builtin::NewYear => BuiltinHoliday::NewYear,
+            Builtin::AdultDay => BuiltinHoliday::AdultDay,
+            Builtin::FoundationDay => BuiltinHoliday::FoundationDay,
+            Builtin::EmperorsBirthday => BuiltinHoliday::EmperorsBirthday,
+            Builtin::VernalEquinox => BuiltinHoliday::VernalEquinox,
+            Builtin::GreeneryDay => BuiltinHoliday::GreeneryDay,
+            Builtin::ShowaDay => BuiltinHoliday::ShowaDay,
+            Builtin::ConstitutionMemorialDay => BuiltinHoliday::ConstitutionMemorialDay,
+            Builtin::ChildrensDay => BuiltinHoliday::ChildrensDay,
+            Builtin::SeaDay => BuiltinHoliday::SeaDay,
+            Builtin::MountainDay => BuiltinHoliday::MountainDay,
+            Builtin::RespectForTheAgedDay => BuiltinHoliday::RespectForTheAgedDay,
+            Builtin::AutumnEquinox => BuiltinHoliday::AutumnEquinox,
+            Builtin::HealthAndSportsDay => BuiltinHoliday::HealthAndSportsDay,
+            Builtin::SportsDay => BuiltinHoliday::SportsDay,
+            Builtin::CultureDay => BuiltinHoliday::CultureDay,
+            Builtin::LaborThanksgivingDay => BuiltinHoliday::LaborThanksgivingDay,
+            Builtin::ExtraHoliday1959 => BuiltinHoliday::ExtraHoliday1959,
+            Builtin::ExtraHoliday1989 => BuiltinHoliday::ExtraHoliday1989,
+            Builtin::ExtraHoliday1990 => BuiltinHoliday::ExtraHoliday1990,
+            Builtin::ExtraHoliday1993 => BuiltinHoliday::ExtraHoliday1993,
+            Builtin::ExtraHoliday2019May => BuiltinHoliday::ExtraHoliday2019May,
+            Builtin::ExtraHoliday2019Oct => BuiltinHoliday::ExtraHoliday2019Oct,
+        }
+    }
+}
+
+impl From<BuiltinHoliday> for Builtin {
+    fn from(b: BuiltinHoliday) -> Self {
+        Builtin::from_public(b)
+    }
 }
 
 /// レジストリに登録されるチェッカー 1 件。
@@ -295,29 +408,55 @@ pub(crate) enum Checker {
     Original(OriginalEntry),
 }
 
-/// 指定したチェッカーで `date` を判定し、祝日ならその名称を返します。
+/// 指定したチェッカーで `date` を判定し、祝日なら `locale` の名称で祝日を返します。
 ///
 /// `all` は同一レジストリの全チェッカー（振替休日・国民の休日が他のチェッカーを参照するため）。
-pub(crate) fn checker_holiday(checker: &Checker, date: Date, all: &[Checker]) -> Option<String> {
+pub(crate) fn checker_holiday(
+    checker: &Checker,
+    date: Date,
+    all: &[Checker],
+    locale: Locale,
+) -> Option<Holiday> {
     match checker {
         Checker::Builtin(b) => {
             if b.is_holiday(date) {
-                Some(b.name().to_string())
+                Some(Holiday::with_kind(
+                    date,
+                    locale::builtin_name(*b, locale),
+                    HolidayKind::Builtin((*b).into()),
+                ))
             } else {
                 None
             }
         }
-        Checker::Transfer => transfer_name(date, all),
+        Checker::Transfer => transfer_origin(date, all).map(|(b, original_date)| {
+            Holiday::with_kind(
+                date,
+                locale::substitute_name(locale::builtin_name(b, locale), locale),
+                HolidayKind::Substitute {
+                    original: b.into(),
+                    original_date,
+                },
+            )
+        }),
         Checker::National => {
             if national_holiday(date, all) {
-                Some("国民の休日".to_string())
+                Some(Holiday::with_kind(
+                    date,
+                    locale::citizens_name(locale),
+                    HolidayKind::Citizens,
+                ))
             } else {
                 None
             }
         }
         Checker::Original(e) => {
             if e.checker.is_holiday(date) {
-                Some(e.checker.holiday_name(date))
+                Some(Holiday::with_kind(
+                    date,
+                    e.checker.localized_holiday_name(date, locale),
+                    HolidayKind::Original,
+                ))
             } else {
                 None
             }
@@ -325,39 +464,58 @@ pub(crate) fn checker_holiday(checker: &Checker, date: Date, all: &[Checker]) ->
     }
 }
 
-/// 指定日に該当するすべての祝日を計算します（キャッシュなし）。
+/// 指定日に該当するすべての祝日を `locale` の名称で計算します（キャッシュなし）。
 ///
 /// `checkers` をレジストリ順に走査し、該当した各チェッカーの祝日を収集します。グローバル API は
 /// レジストリのスナップショットをロックの外でこの関数に渡すため、独自チェッカーの再入呼び出しでも
 /// デッドロックしません。
-pub(crate) fn compute_holidays(checkers: &[Checker], date: Date) -> Vec<Holiday> {
-    let mut result = Vec::new();
-    for checker in checkers {
-        if let Some(name) = checker_holiday(checker, date, checkers) {
-            result.push(Holiday::new(date, name));
-        }
-    }
-    result
+pub(crate) fn compute_holidays(checkers: &[Checker], date: Date, locale: Locale) -> Vec<Holiday> {
+    checkers
+        .iter()
+        .filter_map(|checker| checker_holiday(checker, date, checkers, locale))
+        .collect()
+}
+
+/// 既定のチェッカー（組込み祝日・振替休日・国民の休日）で `date` を判定し、名称がいずれかの
+/// 表記言語で `name` に一致する祝日の種別を返します。一致しなければ [`HolidayKind::Original`]。
+pub(crate) fn infer_kind(date: Date, name: &str) -> HolidayKind {
+    const LOCALES: [Locale; 4] = [
+        Locale::Japanese,
+        Locale::English,
+        Locale::Romaji,
+        Locale::Hiragana,
+    ];
+    let registry = HolidayCheckerRegistry::new();
+    registry
+        .checkers()
+        .iter()
+        .filter_map(|checker| checker_holiday(checker, date, registry.checkers(), Locale::Japanese))
+        .find(|h| {
+            LOCALES
+                .iter()
+                .any(|&locale| h.kind.localized_name(locale).as_deref() == Some(name))
+        })
+        .map_or(HolidayKind::Original, |h| h.kind)
 }
 
 /// `date` に当たる最初の組込み祝日を返します（レジストリ順）。
-fn first_builtin_on(date: Date, all: &[Checker]) -> Option<Builtin> {
+pub(crate) fn first_builtin_on(date: Date, all: &[Checker]) -> Option<Builtin> {
     all.iter().find_map(|c| match c {
         Checker::Builtin(b) if b.is_holiday(date) => Some(*b),
         _ => None,
     })
 }
 
-/// 振替休日の名称を返します（該当しなければ `None`）。
+/// 振替休日であれば、振替の元となった祝日とその日付を返します（該当しなければ `None`）。
 ///
 /// 組込み祝日のみを対象とし、振替休日・国民の休日・独自祝日は対象から除外します。
-pub(crate) fn transfer_name(date: Date, all: &[Checker]) -> Option<String> {
+pub(crate) fn transfer_origin(date: Date, all: &[Checker]) -> Option<(Builtin, Date)> {
     // 1973 年（昭和48年）4 月 12 日 改正・施行。
     if date.year() < 1973 {
         return None;
     }
     // 日曜日に振替休日は存在しない。
-    if date.iso_weekday() == 7 {
+    if date.day_of_week() == Weekday::Sunday {
         return None;
     }
     // 祝日が存在する日に振替休日は存在しない。
@@ -370,8 +528,8 @@ pub(crate) fn transfer_name(date: Date, all: &[Checker]) -> Option<String> {
         match first_builtin_on(current, all) {
             None => return None,
             Some(b) => {
-                if current.iso_weekday() == 7 {
-                    return Some(format!("{} 振替休日", b.name()));
+                if current.day_of_week() == Weekday::Sunday {
+                    return Some((b, current));
                 }
             }
         }
@@ -383,7 +541,7 @@ pub(crate) fn transfer_name(date: Date, all: &[Checker]) -> Option<String> {
 ///
 /// 国民の休日・独自祝日は対象から除外し、組込み祝日と振替休日を対象とします。
 pub(crate) fn national_holiday(date: Date, all: &[Checker]) -> bool {
-    if date.iso_weekday() == 7 {
+    if date.day_of_week() == Weekday::Sunday {
         return false;
     }
 
@@ -391,7 +549,7 @@ pub(crate) fn national_holiday(date: Date, all: &[Checker]) -> bool {
         all.iter().any(|c| match c {
             Checker::National | Checker::Original(_) => false,
             Checker::Builtin(b) => b.is_holiday(d),
-            Checker::Transfer => transfer_name(d, all).is_some(),
+            Checker::Transfer => transfer_origin(d, all).is_some(),
         })
     };
 
@@ -409,10 +567,35 @@ mod tests {
     #[test]
     fn nth_weekday_basic() {
         // 2017 年 1 月の第 2 月曜日は 9 日（成人の日）。
-        assert_eq!(nth_weekday_day(2017, 1, 2, 1), Some(9));
+        assert_eq!(nth_weekday_day(2017, 1, 2, Weekday::Monday), Some(9));
         // 2017 年 7 月の第 3 月曜日は 17 日（海の日）。
-        assert_eq!(nth_weekday_day(2017, 7, 3, 1), Some(17));
-        assert_eq!(nth_weekday_day(2017, 1, 0, 1), None);
-        assert_eq!(nth_weekday_day(2017, 1, 2, 8), None);
+        assert_eq!(nth_weekday_day(2017, 7, 3, Weekday::Monday), Some(17));
+        assert_eq!(nth_weekday_day(2017, 1, 0, Weekday::Monday), None);
+        // 2017 年 1 月に第 5 金曜日は存在しない。
+        assert_eq!(nth_weekday_day(2017, 1, 5, Weekday::Friday), None);
+        assert_eq!(nth_weekday_day(2017, 1, 5, Weekday::Tuesday), Some(31));
+    }
+
+    #[test]
+    fn public_order_matches_registry() {
+        assert_eq!(BuiltinHoliday::ALL.map(Builtin::from), Builtin::ALL);
+    }
+
+    #[test]
+    fn date_in_agrees_with_is_holiday() {
+        for year in 1947..=2100 {
+            for b in Builtin::ALL {
+                let mut scanned = None;
+                let mut date = Date::new(year, 1, 1).unwrap();
+                while date.year() == year {
+                    if b.is_holiday(date) {
+                        assert_eq!(scanned, None, "{b:?} occurs twice in {year}");
+                        scanned = Some(date);
+                    }
+                    date = date.succ();
+                }
+                assert_eq!(b.date_in(year), scanned, "{b:?} in {year}");
+            }
+        }
     }
 }
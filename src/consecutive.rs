@@ -0,0 +1,91 @@
+//! 連休（連続する休日のまとまり）の検出。
+//!
+//! 週末・国民の祝日（振替休日・国民の休日を含む）・登録済みの独自祝日を休日として、
+//! 連続する休日のまとまりを求めます。
+
+use crate::business::{MAX_CONSECUTIVE_CLOSED_DAYS, Weekend};
+use crate::date::Date;
+use crate::model::Holiday;
+use crate::search;
+
+/// 連続する休日のまとまり（連休）。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConsecutiveHolidays {
+    /// 連休の初日。
+    pub start: Date,
+    /// 連休の最終日。
+    pub end: Date,
+    /// 連休中の祝日（週末のみの日は含まない）。
+    pub holidays: Vec<Holiday>,
+}
+
+impl ConsecutiveHolidays {
+    /// 連休の日数（初日と最終日を含む）を返します。
+    pub fn days(&self) -> i64 {
+        self.end.to_ordinal() - self.start.to_ordinal() + 1
+    }
+
+    /// 指定日がこの連休に含まれるかどうかを返します。
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// `start`〜`end`（両端を含む）と重なる連休のうち、`min_days` 日以上のものを返します。
+///
+/// 範囲の端にかかる連休は範囲外まで延ばした本来の長さで返します。ただし範囲外へ延ばすのは
+/// 前後それぞれ [`MAX_CONSECUTIVE_CLOSED_DAYS`] 日までで、独自チェッカーによって休日が
+/// 際限なく続く場合はそこで打ち切ります。
+/// `holidays_on` はその日に該当する祝日を返す関数です。
+pub(crate) fn consecutive_holidays(
+    start: Date,
+    end: Date,
+    min_days: u32,
+    weekend: Weekend,
+    holidays_on: impl Fn(Date) -> Vec<Holiday>,
+) -> Vec<ConsecutiveHolidays> {
+    let off_day = |date: Date| -> Option<Vec<Holiday>> {
+        let holidays = holidays_on(date);
+        (weekend.contains(date) || !holidays.is_empty()).then_some(holidays)
+    };
+
+    // 日付の範囲の端では延ばす範囲を端までに丸め、序数で数えて日付の桁あふれを避ける。
+    let limit = i64::from(MAX_CONSECUTIVE_CLOSED_DAYS);
+    let first_day = (start.to_ordinal() - limit).max(search::first_date().to_ordinal());
+    let last_day = (end.to_ordinal() + limit).min(search::last_date().to_ordinal());
+    let end = end.to_ordinal();
+
+    let mut out = Vec::new();
+    let mut ordinal = start.to_ordinal();
+    // 範囲の開始日が連休の途中なら、連休の初日まで遡る。
+    while ordinal > first_day
+        && off_day(Date::from_ordinal(ordinal - 1)).is_some()
+        && off_day(Date::from_ordinal(ordinal)).is_some()
+    {
+        ordinal -= 1;
+    }
+    while ordinal <= end {
+        let Some(first) = off_day(Date::from_ordinal(ordinal)) else {
+            ordinal += 1;
+            continue;
+        };
+        let block_start = Date::from_ordinal(ordinal);
+        let mut holidays = first;
+        ordinal += 1;
+        while ordinal <= last_day
+            && let Some(more) = off_day(Date::from_ordinal(ordinal))
+        {
+            holidays.extend(more);
+            ordinal += 1;
+        }
+        let block = ConsecutiveHolidays {
+            start: block_start,
+            end: Date::from_ordinal(ordinal - 1),
+            holidays,
+        };
+        if block.days() >= i64::from(min_days) {
+            out.push(block);
+        }
+    }
+    out
+}
@@ -83,6 +83,13 @@ impl Date {
         }
     }
 
+    /// 検証せずに年月日から日付を生成します（定数表のための `const` 版）。
+    ///
+    /// 呼び出し側が実在する日付であることを保証してください。
+    pub(crate) const fn from_ymd_unchecked(year: i32, month: u32, day: u32) -> Self {
+        Date { year, month, day }
+    }
+
     /// 年月日から日付を生成します（[`Date::new`] のエイリアス）。
     #[inline]
     pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Self, DateError> {
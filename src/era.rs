@@ -0,0 +1,208 @@
+//! 和暦（元号）との相互変換。
+//!
+//! 明治以降の元号について、グレゴリオ暦の [`Date`] と「元号・年・月・日」を相互に変換します。
+//! 改元日を境界として元号を切り替え、各元号の初年は「元年」と表記します。
+//!
+//! 明治 5 年（1872 年）までの日本は太陰太陽暦を用いていたため、明治初期の和暦日付は当時の
+//! 暦日とは一致しません。本モジュールは明治元年の開始を慶応 4 年 1 月 1 日に当たる
+//! 1868-01-25 とし、以降を先発グレゴリオ暦で換算します。
+
+use crate::date::Date;
+use crate::error::DateError;
+use std::fmt;
+
+/// 元号。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Era {
+    /// 明治（1868-01-25 〜 1912-07-29）
+    Meiji,
+    /// 大正（1912-07-30 〜 1926-12-24）
+    Taisho,
+    /// 昭和（1926-12-25 〜 1989-01-07）
+    Showa,
+    /// 平成（1989-01-08 〜 2019-04-30）
+    Heisei,
+    /// 令和（2019-05-01 〜）
+    Reiwa,
+}
+
+impl Era {
+    /// 古い順のすべての元号。
+    pub const ALL: [Era; 5] = [Era::Meiji, Era::Taisho, Era::Showa, Era::Heisei, Era::Reiwa];
+
+    /// 元号名を返します（例: `"令和"`）。
+    pub fn name(self) -> &'static str {
+        match self {
+            Era::Meiji => "明治",
+            Era::Taisho => "大正",
+            Era::Showa => "昭和",
+            Era::Heisei => "平成",
+            Era::Reiwa => "令和",
+        }
+    }
+
+    /// 元号のローマ字表記を返します（例: `"Reiwa"`）。
+    pub fn romaji(self) -> &'static str {
+        match self {
+            Era::Meiji => "Meiji",
+            Era::Taisho => "Taisho",
+            Era::Showa => "Showa",
+            Era::Heisei => "Heisei",
+            Era::Reiwa => "Reiwa",
+        }
+    }
+
+    /// 元号の頭文字（アルファベット 1 文字）を返します（例: `'R'`）。
+    pub fn initial(self) -> char {
+        match self {
+            Era::Meiji => 'M',
+            Era::Taisho => 'T',
+            Era::Showa => 'S',
+            Era::Heisei => 'H',
+            Era::Reiwa => 'R',
+        }
+    }
+
+    /// 元号の初日（改元日）を返します。
+    pub fn start(self) -> Date {
+        let (y, m, d) = match self {
+            Era::Meiji => (1868, 1, 25),
+            Era::Taisho => (1912, 7, 30),
+            Era::Showa => (1926, 12, 25),
+            Era::Heisei => (1989, 1, 8),
+            Era::Reiwa => (2019, 5, 1),
+        };
+        Date::new(y, m, d).expect("era start dates are valid calendar dates")
+    }
+
+    /// 元号の最終日を返します（現行の元号なら `None`）。
+    pub fn end(self) -> Option<Date> {
+        self.next().map(|next| next.start().pred())
+    }
+
+    /// 次の元号を返します（現行の元号なら `None`）。
+    pub fn next(self) -> Option<Era> {
+        match self {
+            Era::Meiji => Some(Era::Taisho),
+            Era::Taisho => Some(Era::Showa),
+            Era::Showa => Some(Era::Heisei),
+            Era::Heisei => Some(Era::Reiwa),
+            Era::Reiwa => None,
+        }
+    }
+
+    /// 指定日の元号を返します（明治より前なら `None`）。
+    pub fn of(date: Date) -> Option<Era> {
+        Era::ALL
+            .iter()
+            .rev()
+            .copied()
+            .find(|era| era.start() <= date)
+    }
+
+    /// 元号名から元号を返します（例: `"令和"` → [`Era::Reiwa`]）。
+    pub fn from_name(name: &str) -> Option<Era> {
+        Era::ALL.iter().copied().find(|era| era.name() == name)
+    }
+}
+
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 和暦の年を表記します。初年は `"元年"`、それ以外は `"6年"` のように返します。
+pub fn era_year_label(year: u32) -> String {
+    if year == 1 {
+        "元年".to_string()
+    } else {
+        format!("{year}年")
+    }
+}
+
+/// 和暦で表した日付（元号・年・月・日）。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, Era};
+///
+/// let jd = Date::new(2019, 5, 1).unwrap().to_japanese().unwrap();
+/// assert_eq!((jd.era, jd.year), (Era::Reiwa, 1));
+/// assert_eq!(jd.to_string(), "令和元年5月1日");
+///
+/// assert_eq!(
+///     Date::from_japanese(Era::Heisei, 1, 1, 8).unwrap(),
+///     Date::new(1989, 1, 8).unwrap()
+/// );
+/// assert!(Date::from_japanese(Era::Heisei, 31, 5, 1).is_err()); // 既に令和
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JapaneseDate {
+    /// 元号。
+    pub era: Era,
+    /// 元号の年（元年は 1）。
+    pub year: u32,
+    /// 月（1〜12）。
+    pub month: u32,
+    /// 日（1〜31）。
+    pub day: u32,
+}
+
+impl JapaneseDate {
+    /// グレゴリオ暦の日付へ変換します（[`Date::from_japanese`] と同じ）。
+    pub fn to_date(self) -> Result<Date, DateError> {
+        Date::from_japanese(self.era, self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for JapaneseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}月{}日",
+            self.era.name(),
+            era_year_label(self.year),
+            self.month,
+            self.day
+        )
+    }
+}
+
+impl Date {
+    /// 和暦へ変換します。明治（1868-01-25）より前なら `None`。
+    pub fn to_japanese(self) -> Option<JapaneseDate> {
+        let era = Era::of(self)?;
+        Some(JapaneseDate {
+            era,
+            year: (self.year() - era.start().year() + 1) as u32,
+            month: self.month(),
+            day: self.day(),
+        })
+    }
+
+    /// 和暦（元号・年・月・日）から日付を生成します。
+    ///
+    /// 実在しない年月日は [`DateError::InvalidDate`]、その元号の期間外の日付（例: 平成 31 年
+    /// 5 月 1 日、昭和 64 年 1 月 8 日、0 年）は [`DateError::OutOfEra`] を返します。
+    pub fn from_japanese(era: Era, year: u32, month: u32, day: u32) -> Result<Date, DateError> {
+        let out_of_era = DateError::OutOfEra {
+            era,
+            year,
+            month,
+            day,
+        };
+        if year == 0 {
+            return Err(out_of_era);
+        }
+        let gregorian = i32::try_from(year)
+            .ok()
+            .and_then(|y| era.start().year().checked_add(y - 1))
+            .ok_or(out_of_era.clone())?;
+        let date = Date::new(gregorian, month, day)?;
+        if date < era.start() || era.end().is_some_and(|end| date > end) {
+            return Err(out_of_era);
+        }
+        Ok(date)
+    }
+}
@@ -1,12 +1,18 @@
 //! エラー型。
 //!
 //! 不正な引数型による実行時エラーは型システムによりコンパイル時に排除されるため、
-//! 本モジュールでは実在しない日付を生成しようとした場合のエラーのみを定義します。
+//! 本モジュールでは実在しない日付（および元号の期間外の和暦日付・存在しない旧暦日付）を生成しようとした場合と、
+//! 文字列を日付として解析できなかった場合のエラーのみを定義します。
 
+use crate::era::Era;
 use std::fmt;
 
 /// 日付の生成に失敗したことを表すエラー。
+///
+/// 今後もバリアントを追加する可能性があるため `#[non_exhaustive]` としています。クレート外で
+/// `match` する場合はワイルドカード（`_`）の腕を含めてください。
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DateError {
     /// 実在しない年月日が指定された。
     InvalidDate {
@@ -17,6 +23,45 @@ pub enum DateError {
         /// 指定された日。
         day: u32,
     },
+    /// 和暦の日付が指定した元号の期間外だった。
+    OutOfEra {
+        /// 指定された元号。
+        era: Era,
+        /// 指定された元号の年。
+        year: u32,
+        /// 指定された月。
+        month: u32,
+        /// 指定された日。
+        day: u32,
+    },
+    /// 旧暦に存在しない日付（その年にない閏月など）が指定された。
+    InvalidLunisolarDate {
+        /// 指定された旧暦の年。
+        year: i32,
+        /// 指定された月。
+        month: u32,
+        /// 閏月かどうか。
+        leap: bool,
+        /// 指定された日。
+        day: u32,
+    },
+    /// 文字列の解析中に予期しない文字が現れた。
+    UnexpectedCharacter {
+        /// 問題の文字の位置（0 始まりの文字数）。
+        position: usize,
+        /// 現れた文字。
+        found: char,
+    },
+    /// 文字列が途中で終わった。
+    UnexpectedEnd {
+        /// 入力の終端位置（0 始まりの文字数）。
+        position: usize,
+    },
+    /// 数値が大きすぎて扱えない。
+    InvalidNumber {
+        /// 数値の開始位置（0 始まりの文字数）。
+        position: usize,
+    },
 }
 
 impl fmt::Display for DateError {
@@ -25,6 +70,37 @@ impl fmt::Display for DateError {
             DateError::InvalidDate { year, month, day } => {
                 write!(f, "invalid date: {year:04}-{month:02}-{day:02}")
             }
+            DateError::OutOfEra {
+                era,
+                year,
+                month,
+                day,
+            } => write!(
+                f,
+                "date outside of era: {} {year}-{month:02}-{day:02}",
+                era.romaji()
+            ),
+            DateError::InvalidLunisolarDate {
+                year,
+                month,
+                leap,
+                day,
+            } => {
+                let leap = if *leap { "leap " } else { "" };
+                write!(
+                    f,
+                    "invalid lunisolar date: {year:04}-{leap}{month:02}-{day:02}"
+                )
+            }
+            DateError::UnexpectedCharacter { position, found } => {
+                write!(f, "unexpected character {found:?} at position {position}")
+            }
+            DateError::UnexpectedEnd { position } => {
+                write!(f, "unexpected end of input at position {position}")
+            }
+            DateError::InvalidNumber { position } => {
+                write!(f, "invalid number at position {position}")
+            }
         }
     }
 }
@@ -0,0 +1,122 @@
+//! 東京証券取引所の売買日カレンダー。
+//!
+//! [`crate::TseHoliday`] を登録した [`JPHoliday`] をもとに、売買日の判定・翌売買日・受渡日
+//! （T+N）を計算します。売買日は休業日に加えて臨時の終日売買停止日を除き、受渡日は
+//! 決済の行われる営業日（休業日以外の日）で数えます。
+
+use crate::business;
+use crate::date::Date;
+use crate::jpholiday::JPHoliday;
+
+/// 受渡日の短縮（T+3 → T+2）が適用された最初の約定日。
+const T_PLUS_2_START: (i32, u32, u32) = (2019, 7, 16);
+
+/// 休業日ではないが終日売買が停止された日（2020-10-01 はシステム障害）。
+const TRADING_HALTS: [(i32, u32, u32); 1] = [(2020, 10, 1)];
+
+/// 東京証券取引所の売買日カレンダー。
+///
+/// 土曜日・日曜日・国民の祝日・年末年始（12/31〜1/3、[`crate::TseHoliday`]）を休業日とします。
+/// 終日売買停止日（2020-10-01）は売買日ではありませんが、受渡しは行われるため受渡日の計算では
+/// 営業日として数えます。1989 年 2 月より前の土曜日の立会いは対象外で、土曜日は常に休業日です。
+/// 休業日は長くても数日しか続かないため、売買日・受渡日の計算は常に日付を返します。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, TseCalendar};
+///
+/// let tse = TseCalendar::new();
+/// assert!(!tse.is_trading_day(Date::new(2024, 1, 3).unwrap()));
+/// assert_eq!(
+///     tse.next_trading_day(Date::new(2023, 12, 29).unwrap()),
+///     Date::new(2024, 1, 4).unwrap()
+/// );
+/// // 2019-07-16 以降の約定は T+2。
+/// assert_eq!(
+///     tse.settlement_date(Date::new(2024, 1, 4).unwrap()),
+///     Date::new(2024, 1, 9).unwrap()
+/// );
+/// ```
+pub struct TseCalendar {
+    calendar: JPHoliday,
+}
+
+impl TseCalendar {
+    /// 新しいカレンダーを生成します。
+    pub fn new() -> Self {
+        TseCalendar {
+            calendar: JPHoliday::tse(),
+        }
+    }
+
+    /// 休業日の判定に用いる [`JPHoliday`] を返します（休業日の名称の取得などに利用できます）。
+    pub fn calendar(&self) -> &JPHoliday {
+        &self.calendar
+    }
+
+    /// その日が売買日かどうかを返します。
+    pub fn is_trading_day(&self, date: Date) -> bool {
+        self.calendar.is_business_day(date) && !Self::is_trading_halt(date)
+    }
+
+    /// 休業日ではないが終日売買が停止された日かどうかを返します。
+    pub fn is_trading_halt(date: Date) -> bool {
+        TRADING_HALTS.contains(&(date.year(), date.month(), date.day()))
+    }
+
+    /// `date` より後の最初の売買日を返します。
+    pub fn next_trading_day(&self, date: Date) -> Date {
+        business::expect_business_day(
+            business::next_business_day(date, |d| self.is_trading_day(d)),
+            date,
+        )
+    }
+
+    /// `date` より前の最後の売買日を返します。
+    pub fn previous_trading_day(&self, date: Date) -> Date {
+        business::expect_business_day(
+            business::previous_business_day(date, |d| self.is_trading_day(d)),
+            date,
+        )
+    }
+
+    /// `date` から `days` 売買日後（負なら前）の日付を返します。
+    pub fn add_trading_days(&self, date: Date, days: i64) -> Date {
+        business::expect_business_day(
+            business::add_business_days(date, days, |d| self.is_trading_day(d)),
+            date,
+        )
+    }
+
+    /// 約定日 `trade_date` に適用される株式の標準的な受渡しの営業日数を返します。
+    ///
+    /// 2019-07-16 以降の約定は 2（T+2）、それより前の約定は 3（T+3）。
+    pub fn settlement_cycle(trade_date: Date) -> u32 {
+        let (y, m, d) = T_PLUS_2_START;
+        if trade_date >= Date::new(y, m, d).expect("the T+2 start date is valid") {
+            2
+        } else {
+            3
+        }
+    }
+
+    /// 約定日 `trade_date` の標準的な受渡日（[`TseCalendar::settlement_cycle`] の T+N）を返します。
+    ///
+    /// `trade_date` が休業日の場合も、その日から営業日を数えます。
+    pub fn settlement_date(&self, trade_date: Date) -> Date {
+        self.settlement_date_after(trade_date, Self::settlement_cycle(trade_date))
+    }
+
+    /// 約定日 `trade_date` から `days` 営業日後（T+`days`）の受渡日を返します。
+    ///
+    /// 終日売買停止日も受渡しは行われるため、営業日として数えます。
+    pub fn settlement_date_after(&self, trade_date: Date, days: u32) -> Date {
+        self.calendar.add_business_days(trade_date, i64::from(days))
+    }
+}
+
+impl Default for TseCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,186 @@
+//! 祝日判定の根拠の説明（トレース）。
+//!
+//! 指定日がなぜ祝日なのか（または祝日でないのか）を、該当した規則ごとに構造化して返します。
+//! 組込み祝日は日付の規則・法的根拠・適用年、振替休日は振替元の日曜日の祝日まで遡った日の並び、
+//! 国民の休日は前後で挟む祝日を含みます。
+
+use crate::checker::{self, Checker, checker_holiday};
+use crate::date::Date;
+use crate::locale::{self, Locale};
+use crate::model::{Holiday, HolidayKind};
+use crate::rules::{self, HolidayRule};
+use std::fmt;
+
+/// 祝日判定の根拠の説明。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HolidayExplanation {
+    /// 判定した日付。
+    pub date: Date,
+    /// 該当した規則（レジストリ順）。祝日でなければ空。
+    pub reasons: Vec<HolidayReason>,
+}
+
+impl HolidayExplanation {
+    /// 祝日かどうかを返します。
+    pub fn is_holiday(&self) -> bool {
+        !self.reasons.is_empty()
+    }
+}
+
+/// 祝日に該当した 1 つの規則と、その根拠。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HolidayReason {
+    /// 組込みの国民の祝日。
+    Builtin {
+        /// 該当した祝日。
+        holiday: Holiday,
+        /// その年に適用された規則。
+        rule: HolidayRule,
+    },
+    /// 振替休日。
+    Substitute {
+        /// 該当した振替休日。
+        holiday: Holiday,
+        /// 前日から振替元の日曜日の祝日まで遡った各日の祝日（最後の要素が振替元）。
+        chain: Vec<Holiday>,
+        /// 根拠となる規定。
+        legal_basis: &'static str,
+    },
+    /// 国民の休日（祝日に挟まれた日）。
+    Citizens {
+        /// 該当した国民の休日。
+        holiday: Holiday,
+        /// 前日の祝日。
+        before: Holiday,
+        /// 翌日の祝日。
+        after: Holiday,
+        /// 根拠となる規定。
+        legal_basis: &'static str,
+    },
+    /// 利用者が登録した独自の休日。
+    Original {
+        /// 該当した休日。
+        holiday: Holiday,
+    },
+}
+
+impl HolidayReason {
+    /// 該当した祝日を返します。
+    pub fn holiday(&self) -> &Holiday {
+        match self {
+            HolidayReason::Builtin { holiday, .. }
+            | HolidayReason::Substitute { holiday, .. }
+            | HolidayReason::Citizens { holiday, .. }
+            | HolidayReason::Original { holiday } => holiday,
+        }
+    }
+}
+
+impl fmt::Display for HolidayExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.reasons.is_empty() {
+            return write!(f, "{}: 祝日ではありません", self.date);
+        }
+        for (i, reason) in self.reasons.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let holiday = reason.holiday();
+            write!(f, "{}: {}", self.date, holiday.name)?;
+            match reason {
+                HolidayReason::Builtin { rule, .. } => {
+                    let years = match rule.until {
+                        Some(until) if until == rule.since => format!("{until}年"),
+                        Some(until) => format!("{}〜{until}年", rule.since),
+                        None => format!("{}年〜", rule.since),
+                    };
+                    write!(f, "（{}、{years}、{}）", rule.date_rule, rule.legal_basis)?;
+                }
+                HolidayReason::Substitute {
+                    chain, legal_basis, ..
+                } => {
+                    let days: Vec<String> = chain
+                        .iter()
+                        .map(|h| format!("{} {}", h.date, h.name))
+                        .collect();
+                    write!(f, "（遡り: {}、{}）", days.join(" ← "), legal_basis)?;
+                }
+                HolidayReason::Citizens {
+                    before,
+                    after,
+                    legal_basis,
+                    ..
+                } => {
+                    write!(
+                        f,
+                        "（前日 {} {}・翌日 {} {}、{}）",
+                        before.date, before.name, after.date, after.name, legal_basis
+                    )?;
+                }
+                HolidayReason::Original { .. } => f.write_str("（独自の休日）")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `date` の祝日判定の根拠を `checkers` のレジストリ順に説明します。
+pub(crate) fn explain(date: Date, checkers: &[Checker], locale: Locale) -> HolidayExplanation {
+    let reasons = checkers
+        .iter()
+        .filter_map(|c| {
+            let holiday = checker_holiday(c, date, checkers, locale)?;
+            Some(match holiday.kind {
+                HolidayKind::Builtin(b) => HolidayReason::Builtin {
+                    rule: *rules::rule_for(b.into(), date.year())
+                        .expect("a builtin holiday always has a rule for its year"),
+                    holiday,
+                },
+                HolidayKind::Substitute { original_date, .. } => HolidayReason::Substitute {
+                    chain: substitute_chain(date, original_date, checkers, locale),
+                    legal_basis: rules::substitute_legal_basis(date.year()),
+                    holiday,
+                },
+                HolidayKind::Citizens => HolidayReason::Citizens {
+                    before: sandwiching_holiday(date.pred(), checkers, locale),
+                    after: sandwiching_holiday(date.succ(), checkers, locale),
+                    legal_basis: rules::CITIZENS_LEGAL_BASIS,
+                    holiday,
+                },
+                HolidayKind::Original => HolidayReason::Original { holiday },
+            })
+        })
+        .collect();
+    HolidayExplanation { date, reasons }
+}
+
+/// 振替休日 `date` の前日から振替元 `original_date` まで遡った各日の組込み祝日を返します。
+fn substitute_chain(
+    date: Date,
+    original_date: Date,
+    checkers: &[Checker],
+    locale: Locale,
+) -> Vec<Holiday> {
+    let mut chain = Vec::new();
+    let mut current = date;
+    while current > original_date {
+        current = current.pred();
+        let b = checker::first_builtin_on(current, checkers)
+            .expect("every day walked back by a substitute holiday is a builtin holiday");
+        chain.push(Holiday::with_kind(
+            current,
+            locale::builtin_name(b, locale),
+            HolidayKind::Builtin(b.into()),
+        ));
+    }
+    chain
+}
+
+/// 国民の休日を挟む `date` の祝日（組込み祝日または振替休日）を返します。
+fn sandwiching_holiday(date: Date, checkers: &[Checker], locale: Locale) -> Holiday {
+    checkers
+        .iter()
+        .filter(|c| matches!(c, Checker::Builtin(_) | Checker::Transfer))
+        .find_map(|c| checker_holiday(c, date, checkers, locale))
+        .expect("a citizens' holiday is always sandwiched between holidays")
+}
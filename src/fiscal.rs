@@ -0,0 +1,128 @@
+//! 会計年度（年度）と四半期。
+//!
+//! 年度は開始月を指定でき（既定の 4 月始まりは [`FiscalYear::april`]）、開始日の属する暦年で
+//! 呼びます（4 月始まりの 2025 年度は 2025-04-01〜2026-03-31）。
+
+use crate::date::Date;
+use std::fmt;
+
+/// 開始月を指定した会計年度。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, FiscalYear};
+///
+/// let fy = FiscalYear::of(Date::new(2026, 2, 10).unwrap(), 4).unwrap();
+/// assert_eq!(fy, FiscalYear::april(2025));
+/// assert_eq!(fy.to_string(), "2025年度");
+/// assert_eq!(fy.end(), Date::new(2026, 3, 31).unwrap());
+/// assert_eq!(fy.quarter_of(Date::new(2026, 2, 10).unwrap()), Some(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FiscalYear {
+    year: i32,
+    start_month: u32,
+}
+
+impl FiscalYear {
+    /// `start_month` 月に始まる `year` 年度を生成します。`start_month` が 1〜12 でなければ `None`。
+    pub fn new(year: i32, start_month: u32) -> Option<Self> {
+        (1..=12)
+            .contains(&start_month)
+            .then_some(FiscalYear { year, start_month })
+    }
+
+    /// 4 月に始まる `year` 年度を生成します。
+    pub fn april(year: i32) -> Self {
+        FiscalYear {
+            year,
+            start_month: 4,
+        }
+    }
+
+    /// `date` が属する、`start_month` 月始まりの年度を返します。`start_month` が 1〜12 でなければ `None`。
+    pub fn of(date: Date, start_month: u32) -> Option<Self> {
+        let year = if date.month() >= start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        FiscalYear::new(year, start_month)
+    }
+
+    /// 年度の呼び名となる年（開始日の暦年）を返します。
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// 年度の開始月を返します。
+    pub fn start_month(self) -> u32 {
+        self.start_month
+    }
+
+    /// 年度の初日を返します。
+    pub fn start(self) -> Date {
+        self.month_start(0)
+    }
+
+    /// 年度の最終日を返します。
+    pub fn end(self) -> Date {
+        self.month_start(12).pred()
+    }
+
+    /// 翌年度を返します。
+    pub fn succ(self) -> Self {
+        FiscalYear {
+            year: self.year + 1,
+            ..self
+        }
+    }
+
+    /// 前年度を返します。
+    pub fn pred(self) -> Self {
+        FiscalYear {
+            year: self.year - 1,
+            ..self
+        }
+    }
+
+    /// 指定日がこの年度に含まれるかどうかを返します。
+    pub fn contains(self, date: Date) -> bool {
+        self.start() <= date && date <= self.end()
+    }
+
+    /// 第 `quarter` 四半期（1〜4）の初日と最終日を返します。範囲外なら `None`。
+    pub fn quarter(self, quarter: u32) -> Option<(Date, Date)> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        let offset = (quarter - 1) * 3;
+        Some((
+            self.month_start(offset),
+            self.month_start(offset + 3).pred(),
+        ))
+    }
+
+    /// 指定日がこの年度の第何四半期（1〜4）かを返します。年度に含まれなければ `None`。
+    pub fn quarter_of(self, date: Date) -> Option<u32> {
+        if !self.contains(date) {
+            return None;
+        }
+        let months = (date.month() + 12 - self.start_month) % 12;
+        Some(months / 3 + 1)
+    }
+
+    /// 年度の開始月から `offset` か月後の月の初日を返します。
+    fn month_start(self, offset: u32) -> Date {
+        let index = self.start_month - 1 + offset;
+        let year = self.year + (index / 12) as i32;
+        let month = index % 12 + 1;
+        Date::new(year, month, 1).expect("the first day of a month is always valid")
+    }
+}
+
+impl fmt::Display for FiscalYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}年度", self.year)
+    }
+}
@@ -0,0 +1,339 @@
+//! 日付の文字列解析と書式化。
+//!
+//! ISO 8601（`2024-01-01`、`20240101`）、スラッシュ区切り（`2024/1/1`）、日本語表記
+//! （`2024年1月1日`、`令和6年1月1日`、`令和元年5月1日`）を解析し、パターンによる書式化
+//! （曜日・元号・漢数字を含む）を提供します。
+
+use crate::date::Date;
+use crate::era::Era;
+use crate::error::DateError;
+use std::str::FromStr;
+
+/// 漢数字（0〜9）。
+const KANJI_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// 数値として読み取る最大桁数（`u32` の桁あふれを防ぐ）。
+const MAX_DIGITS: usize = 9;
+
+/// 文字単位で入力を読み進めるカーソル。位置はエラー報告用の 0 始まりの文字位置。
+struct Cursor {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Cursor {
+    fn new(input: &str) -> Self {
+        Cursor {
+            chars: input.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn is_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    /// 現在位置の文字に対する「予期しない文字／入力終端」エラーを返します。
+    fn unexpected(&self) -> DateError {
+        match self.peek() {
+            Some(found) => DateError::UnexpectedCharacter {
+                position: self.pos,
+                found,
+            },
+            None => DateError::UnexpectedEnd { position: self.pos },
+        }
+    }
+
+    /// `expected` を読み飛ばします。
+    fn expect(&mut self, expected: char) -> Result<(), DateError> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// 入力が終端であることを確認します。
+    fn expect_end(&self) -> Result<(), DateError> {
+        if self.is_end() {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// 半角・全角の数字列を読み取り、`(値, 桁数)` を返します。
+    fn number(&mut self) -> Result<(u32, usize), DateError> {
+        let start = self.pos;
+        let mut value: u32 = 0;
+        while let Some(digit) = self.peek().and_then(digit_value) {
+            if self.pos - start == MAX_DIGITS {
+                return Err(DateError::InvalidNumber { position: start });
+            }
+            value = value * 10 + digit;
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.unexpected());
+        }
+        Ok((value, self.pos - start))
+    }
+
+    /// 先頭が元号名なら読み取って返します。
+    fn era(&mut self) -> Option<Era> {
+        Era::ALL.iter().copied().find(|era| {
+            let name: Vec<char> = era.name().chars().collect();
+            if self.chars[self.pos..].starts_with(&name) {
+                self.pos += name.len();
+                true
+            } else {
+                false
+            }
+        })
+    }
+}
+
+/// 半角・全角の数字 1 文字の値を返します。
+fn digit_value(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => c.to_digit(10),
+        '０'..='９' => Some(c as u32 - '０' as u32),
+        _ => None,
+    }
+}
+
+/// 年月日の数値から日付を生成します。年が `i32` に収まらない場合は [`DateError::InvalidNumber`]。
+fn ymd(year: u32, month: u32, day: u32, year_position: usize) -> Result<Date, DateError> {
+    let year = i32::try_from(year).map_err(|_| DateError::InvalidNumber {
+        position: year_position,
+    })?;
+    Date::new(year, month, day)
+}
+
+/// `"6年1月1日"` / `"元年5月1日"` の形の和暦日付を解析します（元号名は読み取り済み）。
+fn parse_era_date(cursor: &mut Cursor, era: Era) -> Result<Date, DateError> {
+    let year = if cursor.peek() == Some('元') {
+        cursor.pos += 1;
+        1
+    } else {
+        cursor.number()?.0
+    };
+    cursor.expect('年')?;
+    let month = cursor.number()?.0;
+    cursor.expect('月')?;
+    let day = cursor.number()?.0;
+    cursor.expect('日')?;
+    cursor.expect_end()?;
+    Date::from_japanese(era, year, month, day)
+}
+
+/// 文字列を日付として解析します。
+fn parse(input: &str) -> Result<Date, DateError> {
+    let mut cursor = Cursor::new(input);
+
+    if let Some(era) = cursor.era() {
+        return parse_era_date(&mut cursor, era);
+    }
+
+    let year_position = cursor.pos;
+    let (year, digits) = cursor.number()?;
+
+    // 区切りのない 8 桁（YYYYMMDD）。
+    if digits == 8 && cursor.is_end() {
+        return ymd(year / 10_000, year / 100 % 100, year % 100, year_position);
+    }
+
+    let (month_sep, day_sep, suffix) = match cursor.peek() {
+        Some('-') => ('-', '-', None),
+        Some('/') => ('/', '/', None),
+        Some('年') => ('年', '月', Some('日')),
+        _ => return Err(cursor.unexpected()),
+    };
+    cursor.expect(month_sep)?;
+    let month = cursor.number()?.0;
+    cursor.expect(day_sep)?;
+    let day = cursor.number()?.0;
+    if let Some(suffix) = suffix {
+        cursor.expect(suffix)?;
+    }
+    cursor.expect_end()?;
+    ymd(year, month, day, year_position)
+}
+
+/// 数値を漢数字の位取り表記（例: 2024 → `"二〇二四"`）にします。
+fn kanji_positional(n: u32) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| KANJI_DIGITS[c.to_digit(10).unwrap_or(0) as usize])
+        .collect()
+}
+
+/// 数値を漢数字の命数表記（例: 25 → `"二十五"`、10 → `"十"`）にします。
+fn kanji_counting(n: u32) -> String {
+    if n == 0 {
+        return KANJI_DIGITS[0].to_string();
+    }
+    if n >= 10_000 {
+        return kanji_positional(n);
+    }
+    let mut out = String::new();
+    for (unit, symbol) in [(1000, '千'), (100, '百'), (10, '十')] {
+        let digit = n / unit % 10;
+        if digit > 1 {
+            out.push(KANJI_DIGITS[digit as usize]);
+        }
+        if digit > 0 {
+            out.push(symbol);
+        }
+    }
+    let ones = n % 10;
+    if ones > 0 {
+        out.push(KANJI_DIGITS[ones as usize]);
+    }
+    out
+}
+
+impl Date {
+    /// 文字列から日付を解析します。
+    ///
+    /// 対応する形式:
+    ///
+    /// - ISO 8601: `2024-01-01`、`20240101`
+    /// - スラッシュ区切り: `2024/01/01`、`2024/1/1`
+    /// - 日本語: `2024年1月1日`、`令和6年1月1日`、`令和元年5月1日`
+    ///
+    /// 数字は全角でも構いません。形式の誤りは、問題の文字位置（0 始まりの文字数）を含む
+    /// [`DateError::UnexpectedCharacter`] / [`DateError::UnexpectedEnd`] /
+    /// [`DateError::InvalidNumber`] を返します。形式が正しくても実在しない日付なら
+    /// [`DateError::InvalidDate`]、元号の期間外なら [`DateError::OutOfEra`] を返します。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, DateError};
+    ///
+    /// let date = Date::new(2024, 1, 1).unwrap();
+    /// assert_eq!(Date::parse("2024-01-01"), Ok(date));
+    /// assert_eq!(Date::parse("令和6年1月1日"), Ok(date));
+    /// assert_eq!("20240101".parse::<Date>(), Ok(date));
+    /// assert_eq!(
+    ///     Date::parse("2024.01.01"),
+    ///     Err(DateError::UnexpectedCharacter { position: 4, found: '.' })
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Date, DateError> {
+        parse(input)
+    }
+
+    /// パターンに従って日付を書式化します。
+    ///
+    /// | 指定子 | 意味 | 例（2019-05-01） |
+    /// |---|---|---|
+    /// | `%Y` | 西暦年（4 桁） | `2019` |
+    /// | `%y` | 西暦年の下 2 桁 | `19` |
+    /// | `%m` / `%-m` | 月（2 桁 / ゼロ埋めなし） | `05` / `5` |
+    /// | `%d` / `%-d` | 日（2 桁 / ゼロ埋めなし） | `01` / `1` |
+    /// | `%a` | 曜日（漢字 1 文字） | `水` |
+    /// | `%A` | 曜日 | `水曜日` |
+    /// | `%E` | 元号名 | `令和` |
+    /// | `%J` | 元号の年（初年は「元」） | `元` |
+    /// | `%%` | `%` そのもの | `%` |
+    ///
+    /// `%KY` / `%Km` / `%Kd` / `%KJ` は年・月・日・元号の年を漢数字で出力します（西暦年は
+    /// `二〇一九` のような位取り、それ以外は `十二` のような表記）。明治より前の日付では
+    /// `%E` / `%J` は空文字列になります。未知の指定子はそのまま出力します。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::Date;
+    ///
+    /// let date = Date::new(2019, 5, 1).unwrap();
+    /// assert_eq!(date.format("%Y/%m/%d(%a)"), "2019/05/01(水)");
+    /// assert_eq!(date.format("%E%J年%-m月%-d日"), "令和元年5月1日");
+    /// assert_eq!(date.format("%KY年%Km月%Kd日"), "二〇一九年五月一日");
+    /// ```
+    pub fn format(self, pattern: &str) -> String {
+        let japanese = self.to_japanese();
+        let mut out = String::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            let mut directive = String::from('%');
+            let flag = match chars.peek() {
+                Some(&f @ ('-' | 'K')) => {
+                    chars.next();
+                    directive.push(f);
+                    Some(f)
+                }
+                _ => None,
+            };
+            let Some(spec) = chars.next() else {
+                out.push_str(&directive);
+                break;
+            };
+            directive.push(spec);
+
+            let era_year = japanese.map(|j| j.year);
+            let rendered = match (flag, spec) {
+                (None, '%') => Some("%".to_string()),
+                (None, 'Y') => Some(format!("{:04}", self.year())),
+                (None, 'y') => Some(format!("{:02}", self.year().rem_euclid(100))),
+                (None, 'm') => Some(format!("{:02}", self.month())),
+                (None, 'd') => Some(format!("{:02}", self.day())),
+                (Some('-'), 'm') => Some(self.month().to_string()),
+                (Some('-'), 'd') => Some(self.day().to_string()),
+                (None, 'a') => Some(self.day_of_week().japanese_short_name().to_string()),
+                (None, 'A') => Some(self.day_of_week().japanese_name().to_string()),
+                (None, 'E') => Some(japanese.map_or("", |j| j.era.name()).to_string()),
+                (None, 'J') => Some(match era_year {
+                    Some(1) => "元".to_string(),
+                    Some(y) => y.to_string(),
+                    None => String::new(),
+                }),
+                (Some('K'), 'Y') => Some(kanji_positional(self.year().unsigned_abs())),
+                (Some('K'), 'm') => Some(kanji_counting(self.month())),
+                (Some('K'), 'd') => Some(kanji_counting(self.day())),
+                (Some('K'), 'J') => Some(match era_year {
+                    Some(1) => "元".to_string(),
+                    Some(y) => kanji_counting(y),
+                    None => String::new(),
+                }),
+                _ => None,
+            };
+            out.push_str(rendered.as_deref().unwrap_or(&directive));
+        }
+        out
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kanji_numerals() {
+        assert_eq!(kanji_counting(1), "一");
+        assert_eq!(kanji_counting(10), "十");
+        assert_eq!(kanji_counting(12), "十二");
+        assert_eq!(kanji_counting(20), "二十");
+        assert_eq!(kanji_counting(31), "三十一");
+        assert_eq!(kanji_counting(64), "六十四");
+        assert_eq!(kanji_counting(100), "百");
+        assert_eq!(kanji_positional(2024), "二〇二四");
+    }
+}
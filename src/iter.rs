@@ -0,0 +1,194 @@
+//! 祝日を日付順に遅延評価で列挙するイテレータ。
+//!
+//! [`crate::holidays_from`] などの関数 API と [`crate::JPHoliday::holidays_from`] などの
+//! インスタンス API が共通して返す [`HolidayIter`] を提供します。`Vec` を確保せずに、
+//! 必要な分だけ（例: `take(5)`）祝日を取り出せます。次の祝日は [`crate::search`] で
+//! 候補日から直接探すため、祝日のない日を 1 日ずつ判定することはありません。
+
+use crate::checker::{Checker, compute_holidays};
+use crate::date::Date;
+use crate::jpholiday::JPHoliday;
+use crate::locale::Locale;
+use crate::model::Holiday;
+use crate::search;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// 祝日の計算元。
+enum Source<'a> {
+    /// グローバル API のレジストリのスナップショット（イテレータ生成時点の登録状態）。
+    Snapshot(Vec<Checker>),
+    /// インスタンス API（キャッシュと表記言語はインスタンスに従う）。
+    Instance(&'a JPHoliday),
+}
+
+impl Source<'_> {
+    /// `locale` が `None` ならグローバル API は日本語、インスタンス API はインスタンスの表記言語。
+    fn holidays(&self, date: Date, locale: Option<Locale>) -> Vec<Holiday> {
+        match (self, locale) {
+            (Source::Snapshot(checkers), locale) => {
+                compute_holidays(checkers, date, locale.unwrap_or_default())
+            }
+            (Source::Instance(jp), Some(locale)) => jp.holidays_in(date, locale),
+            (Source::Instance(jp), None) => jp.holidays(date),
+        }
+    }
+
+    fn checkers(&self) -> &[Checker] {
+        match self {
+            Source::Snapshot(checkers) => checkers,
+            Source::Instance(jp) => jp.checkers(),
+        }
+    }
+}
+
+/// 祝日を日付順（または逆順）に列挙するイテレータ。
+///
+/// 同じ日に複数の祝日が該当する場合はレジストリ順に返します。
+///
+/// # Examples
+/// ```
+/// use jpholiday::Date;
+///
+/// // 2024-01-01 以降の祝日を 3 件だけ取得する。
+/// let next: Vec<String> = jpholiday::holidays_from(Date::new(2024, 1, 1).unwrap())
+///     .take(3)
+///     .map(|h| h.name)
+///     .collect();
+/// assert_eq!(next, ["元日", "成人の日", "建国記念の日"]);
+/// ```
+pub struct HolidayIter<'a> {
+    source: Source<'a>,
+    /// 次に調べる日付（走査終了なら `None`）。
+    cursor: Option<Date>,
+    /// 走査方向の終端（この日付を含む）。
+    limit: Date,
+    forward: bool,
+    /// 名称の表記言語（`None` なら計算元の既定）。
+    locale: Option<Locale>,
+    pending: VecDeque<Holiday>,
+}
+
+impl<'a> HolidayIter<'a> {
+    /// `start` から `end` まで（両端を含む）を日付順に走査します。
+    fn forward(source: Source<'a>, start: Date, end: Date) -> Self {
+        HolidayIter {
+            source,
+            cursor: (start <= end).then_some(start),
+            limit: end,
+            forward: true,
+            locale: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// `start` から過去へ向かって走査します。
+    ///
+    /// 独自チェッカーが登録されていなければ、祝日法の施行日（1948-07-20）より前には祝日が
+    /// 存在しないため、そこで終了します。登録されていれば 1 年 1 月 1 日まで走査します。
+    fn backward(source: Source<'a>, start: Date) -> Self {
+        let limit = Date::from_ordinal(1);
+        HolidayIter {
+            source,
+            cursor: (start >= limit).then_some(start),
+            limit,
+            forward: false,
+            locale: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// 祝日名の表記言語を指定します。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, Locale};
+    ///
+    /// let next = jpholiday::holidays_from(Date::new(2024, 1, 1).unwrap())
+    ///     .with_locale(Locale::English)
+    ///     .next()
+    ///     .unwrap();
+    /// assert_eq!(next.name, "New Year's Day");
+    /// ```
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    pub(crate) fn from_snapshot(checkers: Vec<Checker>, start: Date) -> Self {
+        Self::forward(Source::Snapshot(checkers), start, search::last_date())
+    }
+
+    pub(crate) fn rev_from_snapshot(checkers: Vec<Checker>, start: Date) -> Self {
+        Self::backward(Source::Snapshot(checkers), start)
+    }
+
+    pub(crate) fn between_snapshot(checkers: Vec<Checker>, start: Date, end: Date) -> Self {
+        Self::forward(Source::Snapshot(checkers), start, end)
+    }
+
+    pub(crate) fn from_instance(jp: &'a JPHoliday, start: Date) -> Self {
+        Self::forward(Source::Instance(jp), start, search::last_date())
+    }
+
+    pub(crate) fn rev_from_instance(jp: &'a JPHoliday, start: Date) -> Self {
+        Self::backward(Source::Instance(jp), start)
+    }
+
+    pub(crate) fn between_instance(jp: &'a JPHoliday, start: Date, end: Date) -> Self {
+        Self::forward(Source::Instance(jp), start, end)
+    }
+
+    /// `cursor` 以降（逆順なら以前）で最初に祝日となる日の祝日を、終端を越えない範囲で探します。
+    fn find_holidays(&self, cursor: Date) -> Option<Vec<Holiday>> {
+        let checkers = self.source.checkers();
+        let holidays_on = |date| self.source.holidays(date, self.locale);
+        if self.forward {
+            search::first_holidays_from(cursor, checkers, holidays_on)
+                .filter(|h| h[0].date <= self.limit)
+        } else {
+            search::last_holidays_until(cursor, checkers, holidays_on)
+                .filter(|h| h[0].date >= self.limit)
+        }
+    }
+
+    /// 祝日を返した日の翌日（逆順なら前日）へカーソルを移し、終端に達したら走査を終了します。
+    fn advance(&mut self, date: Date) {
+        self.cursor = if date == self.limit {
+            None
+        } else if self.forward {
+            Some(date.succ())
+        } else {
+            Some(date.pred())
+        };
+    }
+}
+
+impl Iterator for HolidayIter<'_> {
+    type Item = Holiday;
+
+    fn next(&mut self) -> Option<Holiday> {
+        loop {
+            if let Some(holiday) = self.pending.pop_front() {
+                return Some(holiday);
+            }
+            let Some(holidays) = self.find_holidays(self.cursor?) else {
+                self.cursor = None;
+                return None;
+            };
+            self.advance(holidays[0].date);
+            self.pending.extend(holidays);
+        }
+    }
+}
+
+impl fmt::Debug for HolidayIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HolidayIter")
+            .field("cursor", &self.cursor)
+            .field("limit", &self.limit)
+            .field("forward", &self.forward)
+            .field("locale", &self.locale)
+            .finish_non_exhaustive()
+    }
+}
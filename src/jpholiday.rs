@@ -1,14 +1,25 @@
 //! 祝日判定の中核となる [`JPHoliday`] 型。
 
-use crate::checker::{OriginalHolidayChecker, compute_holidays};
-use crate::date::Date;
+use crate::bridge::{self, BridgeSuggestion};
+use crate::business::{self, RollConvention, Weekend};
+use crate::checker::{Checker, OriginalHolidayChecker, compute_holidays};
+use crate::consecutive::{self, ConsecutiveHolidays};
+use crate::date::{Date, days_in_month};
+use crate::explain::{self, HolidayExplanation};
+use crate::fiscal::FiscalYear;
+use crate::iter::HolidayIter;
+use crate::locale::Locale;
 use crate::model::Holiday;
+use crate::period::{self, Period, PeriodDeadline};
+use crate::preset::{BankHoliday, GovernmentHoliday, TseHoliday};
 use crate::registry::HolidayCheckerRegistry;
+use crate::search;
 use std::cell::RefCell;
 use std::collections::HashMap;
 
 /// 日本の祝日を判定するクラス。
 ///
+/// 祝日名はインスタンスの表記言語（既定は日本語、[`JPHoliday::with_locale`] で変更可能）で返します。
 /// 判定結果は日付単位でキャッシュされます。
 /// キャッシュは問い合わせた日付ごとに増えるため、多数の日付を問い合わせる場合はインスタンスを
 /// drop することでまとめて解放できます（グローバル関数 API はキャッシュを持ちません）。
@@ -26,30 +37,111 @@ use std::collections::HashMap;
 /// ```
 pub struct JPHoliday {
     registry: HolidayCheckerRegistry,
+    locale: Locale,
+    weekend: Weekend,
     cache: RefCell<HashMap<Date, Vec<Holiday>>>,
 }
 
 impl JPHoliday {
     /// 既定のチェッカーを備えた新しいインスタンスを生成します。
     pub fn new() -> Self {
+        Self::with_locale(Locale::Japanese)
+    }
+
+    /// 祝日名を `locale` で返すインスタンスを生成します。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday, Locale};
+    ///
+    /// let jp = JPHoliday::with_locale(Locale::English);
+    /// assert_eq!(
+    ///     jp.is_holiday_name(Date::new(2017, 1, 2).unwrap()).as_deref(),
+    ///     Some("New Year's Day (Substitute Holiday)")
+    /// );
+    /// ```
+    pub fn with_locale(locale: Locale) -> Self {
         JPHoliday {
             registry: HolidayCheckerRegistry::new(),
+            locale,
+            weekend: Weekend::default(),
             cache: RefCell::new(HashMap::new()),
         }
     }
 
+    /// 銀行の休業日（[`BankHoliday`]: 12/31〜1/3）を登録したインスタンスを生成します。
+    ///
+    /// 営業日計算は銀行の営業日（土曜日・日曜日・祝日・年末年始を除く日）になります。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday};
+    ///
+    /// let bank = JPHoliday::bank();
+    /// assert!(!bank.is_business_day(Date::new(2025, 1, 3).unwrap()));
+    /// assert_eq!(
+    ///     bank.next_business_day(Date::new(2024, 12, 30).unwrap()),
+    ///     Date::new(2025, 1, 6).unwrap()
+    /// );
+    /// ```
+    pub fn bank() -> Self {
+        let mut jp = Self::new();
+        jp.register(BankHoliday);
+        jp
+    }
+
+    /// 東京証券取引所の休業日（[`TseHoliday`]）を登録したインスタンスを生成します。
+    ///
+    /// 売買日・受渡日の計算には [`crate::TseCalendar`] を利用できます。
+    pub fn tse() -> Self {
+        let mut jp = Self::new();
+        jp.register(TseHoliday);
+        jp
+    }
+
+    /// 行政機関の休日（土曜日・日曜日・祝日と [`GovernmentHoliday`]: 12/29〜1/3）を
+    /// 登録したインスタンスを生成します。
+    ///
+    /// 行政手続の提出期限は [`JPHoliday::deadline`] で計算できます。
+    pub fn government() -> Self {
+        let mut jp = Self::new();
+        jp.register(GovernmentHoliday);
+        jp
+    }
+
+    /// このインスタンスの表記言語を返します。
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// このインスタンスの表記言語を変更します。
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.cache.borrow_mut().clear();
+        self.locale = locale;
+    }
+
     /// その日に該当するすべての祝日を返します。
     pub fn holidays(&self, date: Date) -> Vec<Holiday> {
         if let Some(cached) = self.cache.borrow().get(&date) {
             return cached.clone();
         }
 
-        let result = compute_holidays(self.registry.checkers(), date);
+        let result = compute_holidays(self.registry.checkers(), date, self.locale);
 
         self.cache.borrow_mut().insert(date, result.clone());
         result
     }
 
+    /// その日に該当するすべての祝日を、名称を指定言語にして返します。
+    ///
+    /// インスタンスの表記言語と異なる言語の結果はキャッシュしません。
+    pub fn holidays_in(&self, date: Date, locale: Locale) -> Vec<Holiday> {
+        if locale == self.locale {
+            return self.holidays(date);
+        }
+        compute_holidays(self.registry.checkers(), date, locale)
+    }
+
     /// その日が祝日かどうかを返します。
     pub fn is_holiday(&self, date: Date) -> bool {
         !self.holidays(date).is_empty()
@@ -60,40 +152,332 @@ impl JPHoliday {
         self.holidays(date).into_iter().next().map(|h| h.name)
     }
 
+    /// その日の祝日名を指定言語で返します（複数該当する場合は先頭、該当しなければ `None`）。
+    pub fn is_holiday_name_in(&self, date: Date, locale: Locale) -> Option<String> {
+        self.holidays_in(date, locale)
+            .into_iter()
+            .next()
+            .map(|h| h.name)
+    }
+
     /// その年のすべての祝日を返します。
     pub fn year_holidays(&self, year: i32) -> Vec<Holiday> {
-        let mut out = Vec::new();
-        let mut date = Date::new(year, 1, 1).expect("January 1st is always a valid date");
-        while date.year() == year {
-            out.extend(self.holidays(date));
-            date = date.succ();
-        }
-        out
+        let start = Date::new(year, 1, 1).expect("January 1st is always a valid date");
+        let end = Date::new(year, 12, 31).expect("December 31st is always a valid date");
+        self.between(start, end)
+    }
+
+    /// 年度（[`FiscalYear`]）のすべての祝日を返します。
+    pub fn fiscal_year_holidays(&self, fiscal_year: FiscalYear) -> Vec<Holiday> {
+        self.between(fiscal_year.start(), fiscal_year.end())
     }
 
     /// その月のすべての祝日を返します。`month` が範囲外なら空を返します。
     pub fn month_holidays(&self, year: i32, month: u32) -> Vec<Holiday> {
-        let mut out = Vec::new();
-        let mut date = match Date::new(year, month, 1) {
-            Ok(d) => d,
-            Err(_) => return out,
-        };
-        while date.month() == month {
-            out.extend(self.holidays(date));
-            date = date.succ();
+        match (Date::new(year, month, 1), days_in_month(year, month)) {
+            (Ok(start), Some(dim)) => self.between(start, start.add_days(dim as i64 - 1)),
+            _ => Vec::new(),
         }
-        out
     }
 
     /// 指定範囲（両端を含む）のすべての祝日を返します。
     pub fn between(&self, start: Date, end: Date) -> Vec<Holiday> {
-        let mut out = Vec::new();
-        let mut current = start;
-        while current <= end {
-            out.extend(self.holidays(current));
-            current = current.succ();
-        }
-        out
+        self.holidays_between(start, end).collect()
+    }
+
+    /// `date` 以降（`date` を含む）の祝日を日付順に遅延評価で列挙します。
+    ///
+    /// 終わりのないイテレータなので、`take` や `take_while` で必要な分だけ取り出してください。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday};
+    ///
+    /// let jp = JPHoliday::new();
+    /// let first = jp.holidays_from(Date::new(2024, 1, 2).unwrap()).next().unwrap();
+    /// assert_eq!((first.date, first.name.as_str()), (Date::new(2024, 1, 8).unwrap(), "成人の日"));
+    /// ```
+    pub fn holidays_from(&self, date: Date) -> HolidayIter<'_> {
+        HolidayIter::from_instance(self, date)
+    }
+
+    /// `date` 以前（`date` を含む）の祝日を新しい順に遅延評価で列挙します。
+    ///
+    /// 独自チェッカーが登録されていなければ、祝日法の施行日（1948-07-20）に達した時点で終了します。
+    pub fn holidays_rev_from(&self, date: Date) -> HolidayIter<'_> {
+        HolidayIter::rev_from_instance(self, date)
+    }
+
+    /// 指定範囲（両端を含む）の祝日を日付順に遅延評価で列挙します。
+    pub fn holidays_between(&self, start: Date, end: Date) -> HolidayIter<'_> {
+        HolidayIter::between_instance(self, start, end)
+    }
+
+    /// `date` より後（`date` を含まない）で最初の祝日を返します。
+    ///
+    /// 同じ日に複数の祝日が該当する場合は先頭を返します。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday};
+    ///
+    /// let jp = JPHoliday::new();
+    /// let today = Date::new(2024, 1, 2).unwrap();
+    /// assert_eq!(jp.next_holiday(today).unwrap().name, "成人の日");
+    /// assert_eq!(jp.days_until_next_holiday(today), Some(6));
+    /// ```
+    pub fn next_holiday(&self, date: Date) -> Option<Holiday> {
+        search::next_holidays(date, self.checkers(), |d| self.holidays(d))
+            .and_then(|h| h.into_iter().next())
+    }
+
+    /// `date` より前（`date` を含まない）で最後の祝日を返します。
+    ///
+    /// 同じ日に複数の祝日が該当する場合は先頭を返します。祝日法の施行（1948-07-20）より前で、
+    /// 独自祝日も見つからなければ `None`。独自祝日は `date` の約 10 年前までしか遡りません。
+    pub fn previous_holiday(&self, date: Date) -> Option<Holiday> {
+        search::previous_holidays(date, self.checkers(), |d| self.holidays(d))
+            .and_then(|h| h.into_iter().next())
+    }
+
+    /// `date` が祝日かどうかの根拠を、インスタンスの表記言語と登録済みチェッカーで説明します。
+    ///
+    /// 詳細は [`crate::explain`] を参照してください。
+    pub fn explain(&self, date: Date) -> HolidayExplanation {
+        explain::explain(date, self.checkers(), self.locale)
+    }
+
+    /// `date` から次の祝日（[`JPHoliday::next_holiday`]）までの日数を返します。
+    pub fn days_until_next_holiday(&self, date: Date) -> Option<i64> {
+        self.next_holiday(date)
+            .map(|h| h.date.to_ordinal() - date.to_ordinal())
+    }
+
+    /// 営業日計算に用いる週末の定義を返します（既定は土曜日・日曜日）。
+    pub fn weekend(&self) -> Weekend {
+        self.weekend
+    }
+
+    /// 営業日計算に用いる週末の定義を変更します。
+    pub fn set_weekend(&mut self, weekend: Weekend) {
+        self.weekend = weekend;
+    }
+
+    /// その日が営業日（週末でも祝日でもない日）かどうかを返します。
+    ///
+    /// 登録済みの独自祝日も休業日として扱います。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday};
+    ///
+    /// let jp = JPHoliday::new();
+    /// assert!(!jp.is_business_day(Date::new(2017, 1, 2).unwrap())); // 元日 振替休日
+    /// assert!(jp.is_business_day(Date::new(2017, 1, 4).unwrap()));
+    /// assert_eq!(
+    ///     jp.add_business_days(Date::new(2016, 12, 30).unwrap(), 1),
+    ///     Date::new(2017, 1, 3).unwrap()
+    /// );
+    /// ```
+    pub fn is_business_day(&self, date: Date) -> bool {
+        !self.weekend.contains(date) && !self.is_holiday(date)
+    }
+
+    /// `date` より後の最初の営業日を返します。
+    ///
+    /// # Panics
+    /// 登録した独自チェッカーにより休業日が約 10 年を超えて続く場合（[`OriginalHolidayChecker`] を参照）。
+    /// panic させずに扱うには [`JPHoliday::checked_next_business_day`] を使用してください。
+    pub fn next_business_day(&self, date: Date) -> Date {
+        business::expect_business_day(self.checked_next_business_day(date), date)
+    }
+
+    /// `date` より後の最初の営業日を返します。休業日が約 10 年を超えて続く場合は `None`。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday, OriginalHolidayChecker};
+    ///
+    /// struct Closed;
+    /// impl OriginalHolidayChecker for Closed {
+    ///     fn is_holiday(&self, _date: Date) -> bool {
+    ///         true
+    ///     }
+    ///     fn holiday_name(&self, _date: Date) -> String {
+    ///         "休業".to_string()
+    ///     }
+    /// }
+    ///
+    /// let mut jp = JPHoliday::new();
+    /// jp.register(Closed);
+    /// assert_eq!(jp.checked_next_business_day(Date::new(2024, 1, 1).unwrap()), None);
+    /// ```
+    pub fn checked_next_business_day(&self, date: Date) -> Option<Date> {
+        business::next_business_day(date, |d| self.is_business_day(d))
+    }
+
+    /// `date` より前の最後の営業日を返します。
+    ///
+    /// # Panics
+    /// 登録した独自チェッカーにより休業日が約 10 年を超えて続く場合（[`OriginalHolidayChecker`] を参照）。
+    /// panic させずに扱うには [`JPHoliday::checked_previous_business_day`] を使用してください。
+    pub fn previous_business_day(&self, date: Date) -> Date {
+        business::expect_business_day(self.checked_previous_business_day(date), date)
+    }
+
+    /// `date` より前の最後の営業日を返します。休業日が約 10 年を超えて続く場合は `None`。
+    pub fn checked_previous_business_day(&self, date: Date) -> Option<Date> {
+        business::previous_business_day(date, |d| self.is_business_day(d))
+    }
+
+    /// `date` から `days` 営業日後（負なら前）の日付を返します。`days` が 0 なら `date` のまま。
+    ///
+    /// # Panics
+    /// 登録した独自チェッカーにより休業日が約 10 年を超えて続く場合（[`OriginalHolidayChecker`] を参照）。
+    /// panic させずに扱うには [`JPHoliday::checked_add_business_days`] を使用してください。
+    pub fn add_business_days(&self, date: Date, days: i64) -> Date {
+        business::expect_business_day(self.checked_add_business_days(date, days), date)
+    }
+
+    /// `date` から `days` 営業日後（負なら前）の日付を返します。途中で休業日が約 10 年を超えて
+    /// 続く場合は `None`。
+    pub fn checked_add_business_days(&self, date: Date, days: i64) -> Option<Date> {
+        business::add_business_days(date, days, |d| self.is_business_day(d))
+    }
+
+    /// `start` の翌日から `end` までの営業日数を返します（`end` を含み `start` を含まない）。
+    ///
+    /// `end < start` の場合は負数を返します。`end` が営業日なら
+    /// `add_business_days(start, business_days_between(start, end)) == end` が成り立ちます。
+    pub fn business_days_between(&self, start: Date, end: Date) -> i64 {
+        business::business_days_between(start, end, |d| self.is_business_day(d))
+    }
+
+    /// 年度（[`FiscalYear`]）の四半期ごとの営業日数を第 1〜第 4 四半期の順に返します。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{FiscalYear, JPHoliday};
+    ///
+    /// let jp = JPHoliday::new();
+    /// let days = jp.quarterly_business_days(FiscalYear::april(2024));
+    /// assert_eq!(days, [62, 62, 64, 59]);
+    /// ```
+    pub fn quarterly_business_days(&self, fiscal_year: FiscalYear) -> [i64; 4] {
+        [1, 2, 3, 4].map(|q| {
+            let (start, end) = fiscal_year
+                .quarter(q)
+                .expect("quarters 1 through 4 always exist");
+            self.business_days_between(start.pred(), end)
+        })
+    }
+
+    /// `date` を `convention` に従って営業日へ調整します（週末はインスタンスの定義に従う）。
+    ///
+    /// # Panics
+    /// 登録した独自チェッカーにより休業日が約 10 年を超えて続く場合（[`OriginalHolidayChecker`] を参照）。
+    /// panic させずに扱うには [`JPHoliday::checked_adjust`] を使用してください。
+    pub fn adjust(&self, date: Date, convention: RollConvention) -> Date {
+        business::expect_business_day(self.checked_adjust(date, convention), date)
+    }
+
+    /// `date` を `convention` に従って営業日へ調整します。移動先の営業日が約 10 年以内に
+    /// 見つからない場合は `None`。
+    pub fn checked_adjust(&self, date: Date, convention: RollConvention) -> Option<Date> {
+        business::adjust(date, convention, |d| self.is_business_day(d))
+    }
+
+    /// 期限日 `due` が休業日なら翌営業日に、営業日ならそのまま返します
+    /// （[`RollConvention::Following`] での [`JPHoliday::adjust`] と同じ）。
+    ///
+    /// 行政機関の休日に関する法律第 2 条（期限が行政機関の休日に当たるときは翌日に満了する）
+    /// に従う計算です。[`JPHoliday::government`] のインスタンスで用いると行政手続の
+    /// 提出期限になります。
+    ///
+    /// # Panics
+    /// 登録した独自チェッカーにより休業日が約 10 年を超えて続く場合。panic させずに扱うには
+    /// [`JPHoliday::checked_deadline`] を使用してください。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday};
+    ///
+    /// let gov = JPHoliday::government();
+    /// // 2023-12-28 (木) は開庁日。
+    /// assert_eq!(
+    ///     gov.deadline(Date::new(2023, 12, 28).unwrap()),
+    ///     Date::new(2023, 12, 28).unwrap()
+    /// );
+    /// // 2023-12-29 (金) は年末年始の休日のため 2024-01-04 (木) まで延びる。
+    /// assert_eq!(
+    ///     gov.deadline(Date::new(2023, 12, 29).unwrap()),
+    ///     Date::new(2024, 1, 4).unwrap()
+    /// );
+    /// ```
+    pub fn deadline(&self, due: Date) -> Date {
+        self.adjust(due, RollConvention::Following)
+    }
+
+    /// 期限日 `due` を [`JPHoliday::deadline`] と同じく翌営業日へ延ばします。休業日が約 10 年を
+    /// 超えて続く場合は `None`。
+    pub fn checked_deadline(&self, due: Date) -> Option<Date> {
+        self.checked_adjust(due, RollConvention::Following)
+    }
+
+    /// `start` を初日とする `period` の期間の満了日を民法第 140 条〜第 143 条に従って計算します。
+    ///
+    /// 初日は算入せず、週・月・年は暦に従って応当日の前日（応当日がなければその月の末日）に
+    /// 満了します。末日が休日（このインスタンスの週末・祝日・独自祝日）に当たる場合は
+    /// 翌営業日まで延長します。結果には計算の根拠の説明が含まれます。期間の末日が `Date` の
+    /// 表現できる範囲（`i32` の年）を超える場合や、休業日が約 10 年を超えて続き延長先の営業日が
+    /// 見つからない場合は `None`。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, JPHoliday, Period};
+    ///
+    /// let jp = JPHoliday::government();
+    /// // 2024-01-31 から 1 か月: 2/29 に満了する。
+    /// let result = jp.period_deadline(Date::new(2024, 1, 31).unwrap(), Period::Months(1));
+    /// assert_eq!(result.unwrap().deadline, Date::new(2024, 2, 29).unwrap());
+    /// // 2024-01-23 から 2 週間: 末日 2/6 は平日。
+    /// let result = jp.period_deadline(Date::new(2024, 1, 23).unwrap(), Period::Weeks(2));
+    /// assert_eq!(result.unwrap().deadline, Date::new(2024, 2, 6).unwrap());
+    /// ```
+    pub fn period_deadline(&self, start: Date, period: Period) -> Option<PeriodDeadline> {
+        period::period_deadline(start, period, self)
+    }
+
+    /// `start`〜`end`（両端を含む）と重なる連休のうち、`min_days` 日以上のものを返します。
+    ///
+    /// 週末（インスタンスの定義）・祝日・登録済みの独自祝日を休日とします。範囲の端にかかる
+    /// 連休は範囲外まで延ばした本来の長さで返します。ただし範囲外へ延ばすのは前後それぞれ
+    /// 約 10 年までで、すべての日を休日とする独自チェッカーを登録した場合もそこで打ち切ります。
+    pub fn consecutive_holidays(
+        &self,
+        start: Date,
+        end: Date,
+        min_days: u32,
+    ) -> Vec<ConsecutiveHolidays> {
+        consecutive::consecutive_holidays(start, end, min_days, self.weekend, |d| self.holidays(d))
+    }
+
+    /// `start`〜`end`（両端を含む）の営業日に `leave_days` 日の休暇を取る案を、連休が長い順に返します。
+    ///
+    /// 休暇は連続する営業日に取るものとし、前後の週末（インスタンスの定義）・祝日・登録済みの
+    /// 独自祝日とつながる連休の日数で順位付けします（同じ長さなら早い順）。
+    pub fn bridge_suggestions(
+        &self,
+        start: Date,
+        end: Date,
+        leave_days: u32,
+    ) -> Vec<BridgeSuggestion> {
+        bridge::bridge_suggestions(start, end, leave_days, |d| self.is_business_day(d))
+    }
+
+    /// 登録済みチェッカーの並びを返します。
+    pub(crate) fn checkers(&self) -> &[Checker] {
+        self.registry.checkers()
     }
 
     /// 独自の祝日チェッカーを登録します。
@@ -64,18 +64,58 @@
 #![warn(missing_docs)]
 
 pub mod astronomy;
+mod bridge;
+mod business;
 mod checker;
+mod consecutive;
 mod date;
+mod era;
 mod error;
+mod exchange;
+mod explain;
+mod fiscal;
+mod format;
+mod iter;
 mod jpholiday;
+mod lineage;
+mod locale;
+mod lunisolar;
 mod model;
+mod occurrence;
+mod period;
+mod preset;
 mod registry;
+mod rules;
+mod search;
+mod seasonal;
+mod solar_term;
+mod tax;
+mod weekday;
 
+pub use bridge::BridgeSuggestion;
+pub use business::{RollConvention, Weekend};
 pub use checker::OriginalHolidayChecker;
+pub use consecutive::ConsecutiveHolidays;
 pub use date::{Date, days_in_month, is_leap_year};
+pub use era::{Era, JapaneseDate, era_year_label};
 pub use error::DateError;
+pub use exchange::TseCalendar;
+pub use explain::{HolidayExplanation, HolidayReason};
+pub use fiscal::FiscalYear;
+pub use iter::HolidayIter;
 pub use jpholiday::JPHoliday;
-pub use model::Holiday;
+pub use lineage::{HolidayId, HolidayName};
+pub use locale::Locale;
+pub use lunisolar::LunisolarDate;
+pub use model::{BuiltinHoliday, Holiday, HolidayKind};
+pub use occurrence::HolidayOccurrence;
+pub use period::{Period, PeriodDeadline};
+pub use preset::{BankHoliday, GovernmentHoliday, TseHoliday};
+pub use rules::HolidayRule;
+pub use seasonal::SeasonalDay;
+pub use solar_term::{SolarTerm, SolarTermDate};
+pub use tax::{TaxDeadline, TaxDeadlineKind};
+pub use weekday::Weekday;
 
 use crate::checker::{Checker, compute_holidays};
 use crate::registry::HolidayCheckerRegistry;
@@ -114,7 +154,12 @@ fn snapshot() -> Vec<Checker> {
 
 /// その日に該当するすべての祝日を返します。
 pub fn holidays(date: Date) -> Vec<Holiday> {
-    compute_holidays(&snapshot(), date)
+    holidays_in(date, Locale::Japanese)
+}
+
+/// その日に該当するすべての祝日を、名称を指定言語にして返します。
+pub fn holidays_in(date: Date, locale: Locale) -> Vec<Holiday> {
+    compute_holidays(&snapshot(), date, locale)
 }
 
 /// その日が祝日かどうかを返します。
@@ -127,49 +172,303 @@ pub fn is_holiday_name(date: Date) -> Option<String> {
     holidays(date).into_iter().next().map(|h| h.name)
 }
 
+/// その日の祝日名を指定言語で返します（該当しなければ `None`）。
+pub fn is_holiday_name_in(date: Date, locale: Locale) -> Option<String> {
+    holidays_in(date, locale).into_iter().next().map(|h| h.name)
+}
+
 /// その年のすべての祝日を `(日付, 名称)` のタプルで返します。
 pub fn year_holidays(year: i32) -> Vec<(Date, String)> {
-    let checkers = snapshot();
-    let mut out = Vec::new();
-    let mut date = Date::new(year, 1, 1).expect("January 1st is always a valid date");
-    while date.year() == year {
-        for holiday in compute_holidays(&checkers, date) {
-            out.push(holiday.into_tuple());
-        }
-        date = date.succ();
-    }
-    out
+    year_holidays_in(year, Locale::Japanese)
+}
+
+/// その年のすべての祝日を、名称を指定言語にして `(日付, 名称)` のタプルで返します。
+pub fn year_holidays_in(year: i32, locale: Locale) -> Vec<(Date, String)> {
+    let start = Date::new(year, 1, 1).expect("January 1st is always a valid date");
+    let end = Date::new(year, 12, 31).expect("December 31st is always a valid date");
+    between_in(start, end, locale)
+}
+
+/// 年度（[`FiscalYear`]）のすべての祝日を `(日付, 名称)` のタプルで返します。
+///
+/// # Examples
+/// ```
+/// use jpholiday::FiscalYear;
+///
+/// // 2025 年度（2025-04-01〜2026-03-31）の最初と最後の祝日。
+/// let holidays = jpholiday::fiscal_year_holidays(FiscalYear::april(2025));
+/// assert_eq!(holidays.first().unwrap().1, "昭和の日");
+/// assert_eq!(holidays.last().unwrap().1, "春分の日");
+/// ```
+pub fn fiscal_year_holidays(fiscal_year: FiscalYear) -> Vec<(Date, String)> {
+    fiscal_year_holidays_in(fiscal_year, Locale::Japanese)
+}
+
+/// 年度（[`FiscalYear`]）のすべての祝日を、名称を指定言語にして `(日付, 名称)` のタプルで返します。
+pub fn fiscal_year_holidays_in(fiscal_year: FiscalYear, locale: Locale) -> Vec<(Date, String)> {
+    between_in(fiscal_year.start(), fiscal_year.end(), locale)
 }
 
 /// その月のすべての祝日を `(日付, 名称)` のタプルで返します。`month` が範囲外なら空を返します。
 pub fn month_holidays(year: i32, month: u32) -> Vec<(Date, String)> {
-    let checkers = snapshot();
-    let mut out = Vec::new();
-    let mut date = match Date::new(year, month, 1) {
-        Ok(d) => d,
-        Err(_) => return out,
-    };
-    while date.month() == month {
-        for holiday in compute_holidays(&checkers, date) {
-            out.push(holiday.into_tuple());
-        }
-        date = date.succ();
+    month_holidays_in(year, month, Locale::Japanese)
+}
+
+/// その月のすべての祝日を、名称を指定言語にして `(日付, 名称)` のタプルで返します。
+/// `month` が範囲外なら空を返します。
+pub fn month_holidays_in(year: i32, month: u32, locale: Locale) -> Vec<(Date, String)> {
+    match (Date::new(year, month, 1), days_in_month(year, month)) {
+        (Ok(start), Some(dim)) => between_in(start, start.add_days(dim as i64 - 1), locale),
+        _ => Vec::new(),
     }
-    out
 }
 
 /// 指定範囲（両端を含む）のすべての祝日を `(日付, 名称)` のタプルで返します。
 pub fn between(start: Date, end: Date) -> Vec<(Date, String)> {
+    between_in(start, end, Locale::Japanese)
+}
+
+/// 指定範囲（両端を含む）のすべての祝日を、名称を指定言語にして `(日付, 名称)` のタプルで返します。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, Locale};
+///
+/// let holidays = jpholiday::between_in(
+///     Date::new(2017, 1, 1).unwrap(),
+///     Date::new(2017, 1, 2).unwrap(),
+///     Locale::Romaji,
+/// );
+/// assert_eq!(holidays[1].1, "Ganjitsu Furikae Kyujitsu");
+/// ```
+pub fn between_in(start: Date, end: Date, locale: Locale) -> Vec<(Date, String)> {
+    holidays_between(start, end)
+        .with_locale(locale)
+        .map(Holiday::into_tuple)
+        .collect()
+}
+
+/// `date` 以降（`date` を含む）の祝日を日付順に遅延評価で列挙します。
+///
+/// 終わりのないイテレータなので、`take` や `take_while` で必要な分だけ取り出してください。
+/// 独自チェッカーの登録状態はイテレータ生成時点のものが使われます。
+pub fn holidays_from(date: Date) -> HolidayIter<'static> {
+    HolidayIter::from_snapshot(snapshot(), date)
+}
+
+/// `date` 以前（`date` を含む）の祝日を新しい順に遅延評価で列挙します。
+///
+/// 独自チェッカーが登録されていなければ、祝日法の施行日（1948-07-20）に達した時点で終了します。
+pub fn holidays_rev_from(date: Date) -> HolidayIter<'static> {
+    HolidayIter::rev_from_snapshot(snapshot(), date)
+}
+
+/// 指定範囲（両端を含む）の祝日を日付順に遅延評価で列挙します。
+pub fn holidays_between(start: Date, end: Date) -> HolidayIter<'static> {
+    HolidayIter::between_snapshot(snapshot(), start, end)
+}
+
+/// `date` より後（`date` を含まない）で最初の祝日を返します。
+///
+/// 同じ日に複数の祝日が該当する場合は先頭を返します。振替休日・国民の休日・登録済みの
+/// 独自祝日も対象です。
+///
+/// # Examples
+/// ```
+/// use jpholiday::Date;
+///
+/// let next = jpholiday::next_holiday(Date::new(2017, 1, 1).unwrap()).unwrap();
+/// assert_eq!(next.date, Date::new(2017, 1, 2).unwrap());
+/// assert_eq!(next.name, "元日 振替休日");
+/// ```
+pub fn next_holiday(date: Date) -> Option<Holiday> {
     let checkers = snapshot();
-    let mut out = Vec::new();
-    let mut current = start;
-    while current <= end {
-        for holiday in compute_holidays(&checkers, current) {
-            out.push(holiday.into_tuple());
-        }
-        current = current.succ();
-    }
-    out
+    search::next_holidays(date, &checkers, |d| {
+        compute_holidays(&checkers, d, Locale::Japanese)
+    })
+    .and_then(|h| h.into_iter().next())
+}
+
+/// `date` より前（`date` を含まない）で最後の祝日を返します。
+///
+/// 同じ日に複数の祝日が該当する場合は先頭を返します。祝日法の施行（1948-07-20）より前で、
+/// 独自祝日も見つからなければ `None`。独自祝日は `date` の約 10 年前までしか遡りません。
+pub fn previous_holiday(date: Date) -> Option<Holiday> {
+    let checkers = snapshot();
+    search::previous_holidays(date, &checkers, |d| {
+        compute_holidays(&checkers, d, Locale::Japanese)
+    })
+    .and_then(|h| h.into_iter().next())
+}
+
+/// 組込み祝日の名称（日本語）と年から、その年の日付を返します。
+///
+/// 年内を走査せずに規則から直接求めます。名称が組込み祝日に一致しない場合や、その年に
+/// その祝日がない場合は `None`。振替休日・国民の休日・独自祝日は対象外です。
+///
+/// # Examples
+/// ```
+/// use jpholiday::Date;
+///
+/// assert_eq!(
+///     jpholiday::holiday_date("春分の日", 2040),
+///     Some(Date::new(2040, 3, 20).unwrap())
+/// );
+/// // 2020 年の東京オリンピックによる特例。
+/// assert_eq!(
+///     jpholiday::holiday_date("スポーツの日", 2020),
+///     Some(Date::new(2020, 7, 24).unwrap())
+/// );
+/// assert_eq!(jpholiday::holiday_date("体育の日", 2020), None);
+/// ```
+pub fn holiday_date(name: &str, year: i32) -> Option<Date> {
+    BuiltinHoliday::from_name(name)?.date_in(year)
+}
+
+/// `year` 年の二十四節気の日付と瞬刻（日本標準時）を日付順（小寒〜冬至）に返します。
+///
+/// `year` が [`SolarTerm::SUPPORTED_YEARS`]（1873〜3000 年）の範囲外なら `None`。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, SolarTerm};
+///
+/// let terms = jpholiday::solar_terms(2024).unwrap();
+/// assert_eq!(terms.len(), 24);
+/// assert_eq!(terms[0].term, SolarTerm::MinorCold);
+/// assert_eq!(terms[0].date, Date::new(2024, 1, 6).unwrap());
+/// assert_eq!(terms[23].term, SolarTerm::WinterSolstice);
+/// assert_eq!(terms[23].date, Date::new(2024, 12, 21).unwrap());
+/// ```
+pub fn solar_terms(year: i32) -> Option<Vec<SolarTermDate>> {
+    SolarTerm::ALL
+        .into_iter()
+        .map(|t| t.in_year(year))
+        .collect()
+}
+
+/// `year` 年の雑節を `(雑節, 日付)` のタプルで日付順に返します。
+///
+/// `year` が [`SolarTerm::SUPPORTED_YEARS`]（1873〜3000 年）の範囲外なら `None`。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, SeasonalDay};
+///
+/// let days = jpholiday::seasonal_days(2024).unwrap();
+/// assert_eq!(days.len(), 14);
+/// assert_eq!(days[1], (SeasonalDay::Setsubun, Date::new(2024, 2, 3).unwrap()));
+/// ```
+pub fn seasonal_days(year: i32) -> Option<Vec<(SeasonalDay, Date)>> {
+    let mut days = SeasonalDay::ALL
+        .into_iter()
+        .map(|s| Some((s, s.in_year(year)?)))
+        .collect::<Option<Vec<(SeasonalDay, Date)>>>()?;
+    days.sort_by_key(|&(s, date)| (date, s));
+    Some(days)
+}
+
+/// `date` が祝日かどうかの根拠を説明します。
+///
+/// 該当した規則ごとに、組込み祝日なら日付の規則・法的根拠・適用年を、振替休日なら振替元の
+/// 日曜日の祝日まで遡った日の並びを、国民の休日なら前後で挟む祝日を返します。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, HolidayReason};
+///
+/// let explanation = jpholiday::explain(Date::new(2019, 5, 6).unwrap());
+/// let HolidayReason::Substitute { chain, .. } = &explanation.reasons[0] else {
+///     panic!("2019-05-06 is a substitute holiday");
+/// };
+/// // 5/5 (日・こどもの日) まで遡る。
+/// let names: Vec<&str> = chain.iter().map(|h| h.name.as_str()).collect();
+/// assert_eq!(names, ["こどもの日"]);
+/// ```
+pub fn explain(date: Date) -> HolidayExplanation {
+    let checkers = snapshot();
+    explain::explain(date, &checkers, Locale::Japanese)
+}
+
+/// `date` から次の祝日（[`next_holiday`]）までの日数を返します。
+pub fn days_until_next_holiday(date: Date) -> Option<i64> {
+    next_holiday(date).map(|h| h.date.to_ordinal() - date.to_ordinal())
+}
+
+/// `start`〜`end`（両端を含む）と重なる連休のうち、`min_days` 日以上のものを返します。
+///
+/// 土曜日・日曜日・祝日（登録済みの独自祝日を含む）を休日とします。範囲の端にかかる連休は
+/// 範囲外まで延ばした本来の長さで返します（範囲外へ延ばすのは前後それぞれ約 10 年まで）。
+/// 週末の定義を変える場合は [`JPHoliday::consecutive_holidays`] を利用してください。
+///
+/// # Examples
+/// ```
+/// use jpholiday::Date;
+///
+/// // 2019 年のゴールデンウィーク（10 連休）。
+/// let blocks = jpholiday::consecutive_holidays(
+///     Date::new(2019, 4, 1).unwrap(),
+///     Date::new(2019, 5, 31).unwrap(),
+///     3,
+/// );
+/// assert_eq!(blocks.len(), 1);
+/// assert_eq!(blocks[0].start, Date::new(2019, 4, 27).unwrap());
+/// assert_eq!(blocks[0].days(), 10);
+/// ```
+pub fn consecutive_holidays(start: Date, end: Date, min_days: u32) -> Vec<ConsecutiveHolidays> {
+    let checkers = snapshot();
+    consecutive::consecutive_holidays(start, end, min_days, Weekend::default(), |d| {
+        compute_holidays(&checkers, d, Locale::Japanese)
+    })
+}
+
+/// `start`〜`end`（両端を含む）の営業日に `leave_days` 日の休暇を取る案を、連休が長い順に返します。
+///
+/// 休暇は連続する営業日に取るものとし、前後の土曜日・日曜日・祝日（登録済みの独自祝日を含む）と
+/// つながる連休の日数で順位付けします（同じ長さなら早い順）。連休は範囲外の休日まで延ばして数えます。
+/// 週末の定義を変える場合は [`JPHoliday::bridge_suggestions`] を利用してください。
+///
+/// # Examples
+/// ```
+/// use jpholiday::Date;
+///
+/// // 2025 年のゴールデンウィークに 4 日の休暇を取ると 11 連休。
+/// let plans = jpholiday::bridge_suggestions(
+///     Date::new(2025, 4, 1).unwrap(),
+///     Date::new(2025, 5, 31).unwrap(),
+///     4,
+/// );
+/// assert_eq!(plans[0].start, Date::new(2025, 4, 26).unwrap());
+/// assert_eq!(plans[0].days(), 11);
+/// ```
+pub fn bridge_suggestions(start: Date, end: Date, leave_days: u32) -> Vec<BridgeSuggestion> {
+    let checkers = snapshot();
+    let weekend = Weekend::default();
+    bridge::bridge_suggestions(start, end, leave_days, |d| {
+        !weekend.contains(d) && compute_holidays(&checkers, d, Locale::Japanese).is_empty()
+    })
+}
+
+/// `date` を `convention` に従って営業日へ調整します。
+///
+/// 週末は土曜日・日曜日とし、祝日（登録済みの独自祝日を含む）を休業日として扱います。
+/// 週末の定義を変える場合は [`JPHoliday::adjust`] を利用してください。
+///
+/// # Panics
+/// 登録した独自チェッカーにより休業日が約 10 年を超えて続く場合。panic させずに扱うには
+/// [`checked_adjust`] を使用してください。
+pub fn adjust(date: Date, convention: RollConvention) -> Date {
+    business::expect_business_day(checked_adjust(date, convention), date)
+}
+
+/// `date` を `convention` に従って営業日へ調整します。移動先の営業日が約 10 年以内に
+/// 見つからない場合は `None`。
+pub fn checked_adjust(date: Date, convention: RollConvention) -> Option<Date> {
+    let checkers = snapshot();
+    let weekend = Weekend::default();
+    business::adjust(date, convention, |d| {
+        !weekend.contains(d) && compute_holidays(&checkers, d, Locale::Japanese).is_empty()
+    })
 }
 
 /// 独自の祝日チェッカーをグローバル API に登録します。
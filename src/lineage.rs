@@ -0,0 +1,339 @@
+//! 改称された祝日を年をまたいで同一視するための識別子。
+//!
+//! 祝日は改称や日付の移動を経ることがあるため（体育の日 → スポーツの日、4 月 29 日の
+//! 天皇誕生日 → みどりの日 → 昭和の日）、[`BuiltinHoliday`] とは別に「系譜」単位の安定した
+//! 識別子 [`HolidayId`] を提供します。
+
+use crate::checker::Builtin;
+use crate::date::Date;
+use crate::model::{BuiltinHoliday, Holiday, HolidayKind};
+use crate::rules;
+use std::fmt;
+
+/// 系譜単位の祝日の識別子。改称前後の祝日は同じ識別子になります。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{BuiltinHoliday, HolidayId};
+///
+/// // 4 月 29 日の祝日は天皇誕生日 → みどりの日 → 昭和の日と改称された。
+/// assert_eq!(HolidayId::of(BuiltinHoliday::EmperorsBirthday, 1988), Some(HolidayId::ShowaDay));
+/// assert_eq!(HolidayId::of(BuiltinHoliday::GreeneryDay, 1990), Some(HolidayId::ShowaDay));
+/// assert_eq!(HolidayId::ShowaDay.name_in(1995), Some("みどりの日"));
+/// // 1989 年以降の天皇誕生日は別の系譜。
+/// assert_eq!(
+///     HolidayId::of(BuiltinHoliday::EmperorsBirthday, 2024),
+///     Some(HolidayId::EmperorsBirthday)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HolidayId {
+    /// 元日
+    NewYear,
+    /// 成人の日
+    AdultDay,
+    /// 建国記念の日
+    FoundationDay,
+    /// 天皇誕生日（1989 年以降の 12 月 23 日・2 月 23 日）
+    EmperorsBirthday,
+    /// 春分の日
+    VernalEquinox,
+    /// 4 月 29 日の祝日（天皇誕生日 → みどりの日 → 昭和の日）
+    ShowaDay,
+    /// みどりの日（2007 年以降の 5 月 4 日）
+    GreeneryDay,
+    /// 憲法記念日
+    ConstitutionMemorialDay,
+    /// こどもの日
+    ChildrensDay,
+    /// 海の日
+    SeaDay,
+    /// 山の日
+    MountainDay,
+    /// 敬老の日
+    RespectForTheAgedDay,
+    /// 秋分の日
+    AutumnEquinox,
+    /// スポーツの日（体育の日 → スポーツの日）
+    SportsDay,
+    /// 文化の日
+    CultureDay,
+    /// 勤労感謝の日
+    LaborThanksgivingDay,
+    /// 1959 年 皇太子・明仁親王の結婚の儀
+    ExtraHoliday1959,
+    /// 1989 年 昭和天皇の大喪の礼
+    ExtraHoliday1989,
+    /// 1990 年 即位の礼正殿の儀
+    ExtraHoliday1990,
+    /// 1993 年 皇太子・皇太子徳仁親王の結婚の儀
+    ExtraHoliday1993,
+    /// 2019 年 5 月 1 日 天皇の即位の日
+    ExtraHoliday2019May,
+    /// 2019 年 10 月 22 日 即位礼正殿の儀
+    ExtraHoliday2019Oct,
+}
+
+/// 系譜の中の 1 つの名称と、その名称の祝日が存在した期間。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayName {
+    /// 名称（例: `"体育の日"`）。
+    pub name: &'static str,
+    /// この名称に当たる組込み祝日。
+    pub holiday: BuiltinHoliday,
+    /// この名称の祝日が最初にあった年。
+    pub since: i32,
+    /// この名称の祝日が最後にあった年（現行の名称なら `None`）。
+    pub until: Option<i32>,
+    /// 前の名称から改称された日（系譜の最初の名称なら `None`）。
+    pub renamed_on: Option<Date>,
+}
+
+impl HolidayName {
+    /// `year` がこの名称の期間に含まれるかどうかを返します。
+    pub fn applies_to(&self, year: i32) -> bool {
+        self.since <= year && self.until.is_none_or(|until| year <= until)
+    }
+}
+
+/// 組込み祝日 `holiday` の規則表（[`rules::rules_of`]）のうち `first` 番目から `last` 番目
+/// （`None` なら最後）までの規則の適用年を期間とする名称を生成します。
+///
+/// 名称は組込み祝日の名称、期間は規則表から求めるため、系譜の表には改称の区切りだけを記述します。
+const fn entry(
+    holiday: BuiltinHoliday,
+    first: usize,
+    last: Option<usize>,
+    renamed_on: Option<Date>,
+) -> HolidayName {
+    let builtin = Builtin::from_public(holiday);
+    let rules = rules::rules_of(builtin);
+    let last = match last {
+        Some(last) => last,
+        None => rules.len() - 1,
+    };
+    HolidayName {
+        name: builtin.name(),
+        holiday,
+        since: rules[first].since,
+        until: rules[last].until,
+        renamed_on,
+    }
+}
+
+/// 規則表のすべての規則の適用年を期間とする、改称のない名称を生成します。
+const fn whole(holiday: BuiltinHoliday) -> HolidayName {
+    entry(holiday, 0, None, None)
+}
+
+/// 改称日（定数表で用いるため `Date::new` を経由せずに生成する）。
+const fn renamed(year: i32, month: u32, day: u32) -> Option<Date> {
+    Some(Date::from_ymd_unchecked(year, month, day))
+}
+
+impl HolidayId {
+    /// すべての識別子。
+    pub const ALL: [HolidayId; 22] = [
+        HolidayId::NewYear,
+        HolidayId::AdultDay,
+        HolidayId::FoundationDay,
+        HolidayId::EmperorsBirthday,
+        HolidayId::VernalEquinox,
+        HolidayId::ShowaDay,
+        HolidayId::GreeneryDay,
+        HolidayId::ConstitutionMemorialDay,
+        HolidayId::ChildrensDay,
+        HolidayId::SeaDay,
+        HolidayId::MountainDay,
+        HolidayId::RespectForTheAgedDay,
+        HolidayId::AutumnEquinox,
+        HolidayId::SportsDay,
+        HolidayId::CultureDay,
+        HolidayId::LaborThanksgivingDay,
+        HolidayId::ExtraHoliday1959,
+        HolidayId::ExtraHoliday1989,
+        HolidayId::ExtraHoliday1990,
+        HolidayId::ExtraHoliday1993,
+        HolidayId::ExtraHoliday2019May,
+        HolidayId::ExtraHoliday2019Oct,
+    ];
+
+    /// この系譜の名称の変遷を年代順に返します。
+    pub fn lineage(self) -> &'static [HolidayName] {
+        use BuiltinHoliday as B;
+        match self {
+            HolidayId::NewYear => const { &[whole(B::NewYear)] },
+            HolidayId::AdultDay => const { &[whole(B::AdultDay)] },
+            HolidayId::FoundationDay => const { &[whole(B::FoundationDay)] },
+            // 1988 年までの 4 月 29 日の規則は昭和の日の系譜に属する。
+            HolidayId::EmperorsBirthday => const { &[entry(B::EmperorsBirthday, 1, None, None)] },
+            HolidayId::VernalEquinox => const { &[whole(B::VernalEquinox)] },
+            HolidayId::ShowaDay => {
+                const {
+                    &[
+                        entry(B::EmperorsBirthday, 0, Some(0), None),
+                        entry(B::GreeneryDay, 0, Some(0), renamed(1989, 2, 17)),
+                        entry(B::ShowaDay, 0, None, renamed(2007, 1, 1)),
+                    ]
+                }
+            }
+            // 2006 年までの 4 月 29 日の規則は昭和の日の系譜に属する。
+            HolidayId::GreeneryDay => const { &[entry(B::GreeneryDay, 1, None, None)] },
+            HolidayId::ConstitutionMemorialDay => const { &[whole(B::ConstitutionMemorialDay)] },
+            HolidayId::ChildrensDay => const { &[whole(B::ChildrensDay)] },
+            HolidayId::SeaDay => const { &[whole(B::SeaDay)] },
+            HolidayId::MountainDay => const { &[whole(B::MountainDay)] },
+            HolidayId::RespectForTheAgedDay => const { &[whole(B::RespectForTheAgedDay)] },
+            HolidayId::AutumnEquinox => const { &[whole(B::AutumnEquinox)] },
+            HolidayId::SportsDay => {
+                const {
+                    &[
+                        whole(B::HealthAndSportsDay),
+                        entry(B::SportsDay, 0, None, renamed(2020, 1, 1)),
+                    ]
+                }
+            }
+            HolidayId::CultureDay => const { &[whole(B::CultureDay)] },
+            HolidayId::LaborThanksgivingDay => const { &[whole(B::LaborThanksgivingDay)] },
+            HolidayId::ExtraHoliday1959 => const { &[whole(B::ExtraHoliday1959)] },
+            HolidayId::ExtraHoliday1989 => const { &[whole(B::ExtraHoliday1989)] },
+            HolidayId::ExtraHoliday1990 => const { &[whole(B::ExtraHoliday1990)] },
+            HolidayId::ExtraHoliday1993 => const { &[whole(B::ExtraHoliday1993)] },
+            HolidayId::ExtraHoliday2019May => const { &[whole(B::ExtraHoliday2019May)] },
+            HolidayId::ExtraHoliday2019Oct => const { &[whole(B::ExtraHoliday2019Oct)] },
+        }
+    }
+
+    /// `year` 年の組込み祝日 `holiday` が属する系譜を返します。その年にその祝日がなければ `None`。
+    pub fn of(holiday: BuiltinHoliday, year: i32) -> Option<HolidayId> {
+        holiday.rule_for(year)?;
+        HolidayId::ALL.into_iter().find(|id| {
+            id.lineage()
+                .iter()
+                .any(|n| n.holiday == holiday && n.applies_to(year))
+        })
+    }
+
+    /// 過去・現在のいずれかの名称が `name` である系譜を返します。
+    ///
+    /// 同じ名称が別の系譜で使われたことがある場合（`"天皇誕生日"`、`"みどりの日"`）は
+    /// 複数を返します。年を指定して絞り込むには [`HolidayId::from_name_in`] を利用してください。
+    pub fn from_name(name: &str) -> Vec<HolidayId> {
+        HolidayId::ALL
+            .into_iter()
+            .filter(|id| id.lineage().iter().any(|n| n.name == name))
+            .collect()
+    }
+
+    /// `year` 年に `name` という名称だった系譜を返します。
+    pub fn from_name_in(name: &str, year: i32) -> Option<HolidayId> {
+        HolidayId::ALL.into_iter().find(|id| {
+            id.lineage()
+                .iter()
+                .any(|n| n.name == name && n.applies_to(year))
+        })
+    }
+
+    /// `year` 年の名称を返します。その年がこの系譜の期間外なら `None`。
+    pub fn name_in(self, year: i32) -> Option<&'static str> {
+        self.lineage()
+            .iter()
+            .find(|n| n.applies_to(year))
+            .map(|n| n.name)
+    }
+
+    /// `year` 年のこの系譜の祝日の日付を返します。その年に祝日がなければ `None`。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, HolidayId};
+    ///
+    /// // 1988 年は天皇誕生日、2000 年はみどりの日として 4 月 29 日。
+    /// assert_eq!(HolidayId::ShowaDay.date_in(1988), Some(Date::new(1988, 4, 29).unwrap()));
+    /// assert_eq!(HolidayId::SportsDay.date_in(2019), Some(Date::new(2019, 10, 14).unwrap()));
+    /// ```
+    pub fn date_in(self, year: i32) -> Option<Date> {
+        self.lineage()
+            .iter()
+            .find(|n| n.applies_to(year))?
+            .holiday
+            .date_in(year)
+    }
+
+    /// 最新の名称を返します。
+    pub fn current_name(self) -> &'static str {
+        self.lineage()
+            .last()
+            .expect("every lineage has at least one name")
+            .name
+    }
+}
+
+impl fmt::Display for HolidayId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.current_name())
+    }
+}
+
+impl Holiday {
+    /// この祝日の系譜の識別子を返します。
+    ///
+    /// 組込みの国民の祝日のみが対象で、振替休日・国民の休日・独自の休日は `None`
+    /// （振替休日の振替元は [`HolidayKind::Substitute`] の `original` から [`HolidayId::of`] で求められます）。
+    pub fn id(&self) -> Option<HolidayId> {
+        match self.kind {
+            HolidayKind::Builtin(b) => HolidayId::of(b, self.date.year()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_builtin_occurrence_has_exactly_one_lineage() {
+        for b in BuiltinHoliday::ALL {
+            for year in 1948..=2100 {
+                let ids = HolidayId::ALL
+                    .into_iter()
+                    .filter(|id| {
+                        id.lineage()
+                            .iter()
+                            .any(|n| n.holiday == b && n.applies_to(year))
+                    })
+                    .count();
+                // 名称の期間は祝日のある年を含む（天皇誕生日の 2019 年のように祝日がない年も含み得る）。
+                if b.rule_for(year).is_some() {
+                    assert_eq!(ids, 1, "{b:?} in {year}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn periods_match_the_years_with_holidays() {
+        for id in HolidayId::ALL {
+            let lineage = id.lineage();
+            for n in lineage {
+                assert!(n.holiday.date_in(n.since).is_some(), "{id:?}");
+                if let Some(until) = n.until {
+                    assert!(n.holiday.date_in(until).is_some(), "{id:?}");
+                }
+            }
+            for pair in lineage.windows(2) {
+                assert!(pair[0].until.is_some_and(|until| until < pair[1].since));
+            }
+        }
+    }
+
+    #[test]
+    fn names_match_builtin_names() {
+        for id in HolidayId::ALL {
+            for n in id.lineage() {
+                assert_eq!(n.name, n.holiday.name(), "{id:?}");
+            }
+        }
+    }
+}
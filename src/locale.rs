@@ -0,0 +1,122 @@
+//! 祝日名の多言語表記。
+//!
+//! 組込みの祝日・振替休日・国民の休日について、日本語・英語・ローマ字・ひらがな（読み）の
+//! 表記を提供します。英語名は内閣府の英語表記に準じます。
+
+use crate::checker::Builtin;
+
+/// 祝日名の表記言語。既定は日本語。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// 日本語（例: `"元日"`）。
+    #[default]
+    Japanese,
+    /// 英語（例: `"New Year's Day"`）。
+    English,
+    /// ローマ字（例: `"Ganjitsu"`）。
+    Romaji,
+    /// ひらがなの読み（例: `"がんじつ"`）。
+    Hiragana,
+}
+
+/// 組込み祝日の `(英語, ローマ字, ひらがな)` 表記。
+fn builtin_names(b: Builtin) -> (&'static str, &'static str, &'static str) {
+    match b {
+        Builtin::NewYear => ("New Year's Day", "Ganjitsu", "がんじつ"),
+        Builtin::AdultDay => ("Coming of Age Day", "Seijin no Hi", "せいじんのひ"),
+        Builtin::FoundationDay => (
+            "National Foundation Day",
+            "Kenkoku Kinen no Hi",
+            "けんこくきねんのひ",
+        ),
+        Builtin::EmperorsBirthday => (
+            "The Emperor's Birthday",
+            "Tenno Tanjobi",
+            "てんのうたんじょうび",
+        ),
+        Builtin::VernalEquinox => ("Vernal Equinox Day", "Shunbun no Hi", "しゅんぶんのひ"),
+        Builtin::GreeneryDay => ("Greenery Day", "Midori no Hi", "みどりのひ"),
+        Builtin::ShowaDay => ("Showa Day", "Showa no Hi", "しょうわのひ"),
+        Builtin::ConstitutionMemorialDay => (
+            "Constitution Memorial Day",
+            "Kenpo Kinenbi",
+            "けんぽうきねんび",
+        ),
+        Builtin::ChildrensDay => ("Children's Day", "Kodomo no Hi", "こどものひ"),
+        Builtin::SeaDay => ("Marine Day", "Umi no Hi", "うみのひ"),
+        Builtin::MountainDay => ("Mountain Day", "Yama no Hi", "やまのひ"),
+        Builtin::RespectForTheAgedDay => {
+            ("Respect for the Aged Day", "Keiro no Hi", "けいろうのひ")
+        }
+        Builtin::AutumnEquinox => ("Autumnal Equinox Day", "Shubun no Hi", "しゅうぶんのひ"),
+        Builtin::HealthAndSportsDay => ("Health and Sports Day", "Taiiku no Hi", "たいいくのひ"),
+        Builtin::SportsDay => ("Sports Day", "Supotsu no Hi", "すぽーつのひ"),
+        Builtin::CultureDay => ("Culture Day", "Bunka no Hi", "ぶんかのひ"),
+        Builtin::LaborThanksgivingDay => (
+            "Labor Thanksgiving Day",
+            "Kinro Kansha no Hi",
+            "きんろうかんしゃのひ",
+        ),
+        Builtin::ExtraHoliday1959 => (
+            "The Rite of Wedding of Crown Prince Akihito",
+            "Kotaishi Akihito Shinno no Kekkon no Gi",
+            "こうたいし・あきひとしんのうのけっこんのぎ",
+        ),
+        Builtin::ExtraHoliday1989 => (
+            "The Funeral Ceremony of Emperor Showa",
+            "Showa Tenno no Taiso no Rei",
+            "しょうわてんのうのたいそうのれい",
+        ),
+        Builtin::ExtraHoliday1990 => (
+            "The Ceremony of the Enthronement of Emperor Akihito",
+            "Sokui no Rei Seiden no Gi",
+            "そくいのれいせいでんのぎ",
+        ),
+        Builtin::ExtraHoliday1993 => (
+            "The Rite of Wedding of Crown Prince Naruhito",
+            "Kotaishi Kotaishi Naruhito Shinno no Kekkon no Gi",
+            "こうたいし・こうたいしなるひとしんのうのけっこんのぎ",
+        ),
+        Builtin::ExtraHoliday2019May => (
+            "The Day of the Emperor's Enthronement",
+            "Tenno no Sokui no Hi",
+            "てんのうのそくいのひ",
+        ),
+        Builtin::ExtraHoliday2019Oct => (
+            "The Ceremony of the Enthronement of Emperor Naruhito",
+            "Sokuirei Seiden no Gi",
+            "そくいれいせいでんのぎ",
+        ),
+    }
+}
+
+/// 組込み祝日の指定言語での名称を返します。
+pub(crate) fn builtin_name(b: Builtin, locale: Locale) -> &'static str {
+    let (english, romaji, hiragana) = builtin_names(b);
+    match locale {
+        Locale::Japanese => b.name(),
+        Locale::English => english,
+        Locale::Romaji => romaji,
+        Locale::Hiragana => hiragana,
+    }
+}
+
+/// 振替休日の指定言語での名称を返します（`original` は振替元の祝日の同言語での名称）。
+pub(crate) fn substitute_name(original: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Japanese => format!("{original} 振替休日"),
+        Locale::English => format!("{original} (Substitute Holiday)"),
+        Locale::Romaji => format!("{original} Furikae Kyujitsu"),
+        Locale::Hiragana => format!("{original} ふりかえきゅうじつ"),
+    }
+}
+
+/// 国民の休日の指定言語での名称を返します。
+pub(crate) fn citizens_name(locale: Locale) -> &'static str {
+    match locale {
+        Locale::Japanese => "国民の休日",
+        Locale::English => "Citizens' Holiday",
+        Locale::Romaji => "Kokumin no Kyujitsu",
+        Locale::Hiragana => "こくみんのきゅうじつ",
+    }
+}
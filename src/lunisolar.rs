@@ -0,0 +1,211 @@
+//! 旧暦（天保暦に準じた太陰太陽暦）との相互変換。
+//!
+//! 朔（新月）の日を月の初日とし、中気を含む月に中気に応じた月番号を与えます。冬至を含む月を
+//! 11 月とし、ある 11 月から次の 11 月までに 13 か月ある場合は、その中で最初に中気を含まない月を
+//! 閏月（前月と同じ番号）とします。日付は日本標準時で判定します。
+//!
+//! 1900〜2100 年を対象とします。朔・中気の瞬刻が日付の境目に近い年は、公表されている暦と
+//! 1 日ずれることがあります。
+
+use crate::astronomy;
+use crate::date::Date;
+use crate::error::DateError;
+use crate::solar_term::SolarTerm;
+use std::fmt;
+
+/// 対応する最初の年。
+const FIRST_YEAR: i32 = 1900;
+/// 対応する最後の年。
+const LAST_YEAR: i32 = 2100;
+
+/// 旧暦の日付。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, LunisolarDate};
+///
+/// // 2024 年の旧正月。
+/// let lunar = Date::new(2024, 2, 10).unwrap().to_lunisolar().unwrap();
+/// assert_eq!((lunar.year, lunar.month, lunar.leap, lunar.day), (2024, 1, false, 1));
+/// assert_eq!(lunar.to_string(), "2024年1月1日");
+///
+/// // 2023 年の閏 2 月 1 日。
+/// let leap = LunisolarDate { year: 2023, month: 2, leap: true, day: 1 };
+/// assert_eq!(leap.to_date(), Ok(Date::new(2023, 3, 22).unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LunisolarDate {
+    /// 旧暦の年（1 月 1 日の属する西暦年）。
+    pub year: i32,
+    /// 月（1〜12）。
+    pub month: u32,
+    /// 閏月かどうか。
+    pub leap: bool,
+    /// 日（1〜30）。
+    pub day: u32,
+}
+
+impl LunisolarDate {
+    /// 新暦（グレゴリオ暦）の日付へ変換します。
+    pub fn to_date(self) -> Result<Date, DateError> {
+        Date::from_lunisolar(self.year, self.month, self.leap, self.day)
+    }
+}
+
+impl fmt::Display for LunisolarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let leap = if self.leap { "閏" } else { "" };
+        write!(f, "{}年{leap}{}月{}日", self.year, self.month, self.day)
+    }
+}
+
+impl Date {
+    /// 旧暦へ変換します。1900〜2100 年の範囲外なら `None`。
+    pub fn to_lunisolar(self) -> Option<LunisolarDate> {
+        if !(FIRST_YEAR..=LAST_YEAR).contains(&self.year()) {
+            return None;
+        }
+        // 冬至を含む月（11 月）の前後どちらの区間に属するかで、月の並びを求める区間を決める。
+        let cycle = if new_moon_index(self) >= eleventh_month_index(self.year()) {
+            self.year() + 1
+        } else {
+            self.year()
+        };
+        let month = cycle_months(cycle)
+            .into_iter()
+            .rev()
+            .find(|m| m.start <= self)
+            .expect("every date falls within a month of its cycle");
+        Some(LunisolarDate {
+            year: month.year,
+            month: month.number,
+            leap: month.leap,
+            day: (self.to_ordinal() - month.start.to_ordinal() + 1) as u32,
+        })
+    }
+
+    /// 旧暦（年・月・閏月かどうか・日）から日付を生成します。
+    ///
+    /// 存在しない月（その年にない閏月など）・日（小の月の 30 日など）や、新暦で 1900〜2100 年の範囲外の日付は
+    /// [`DateError::InvalidLunisolarDate`] を返します。
+    pub fn from_lunisolar(year: i32, month: u32, leap: bool, day: u32) -> Result<Date, DateError> {
+        let invalid = DateError::InvalidLunisolarDate {
+            year,
+            month,
+            leap,
+            day,
+        };
+        // 1900 年 1 月は旧暦では前年（1899 年）の 12 月に当たる。
+        if !(FIRST_YEAR - 1..=LAST_YEAR).contains(&year) || !(1..=12).contains(&month) || day == 0 {
+            return Err(invalid);
+        }
+        // 11 月・12 月（と閏 11 月・閏 12 月）は翌年の冬至までの区間に属する。
+        let cycle = if month >= 11 { year + 1 } else { year };
+        let month = cycle_months(cycle)
+            .into_iter()
+            .find(|m| m.year == year && m.number == month && m.leap == leap)
+            .ok_or_else(|| invalid.clone())?;
+        let date = month.start.add_days(day as i64 - 1);
+        if day > month.length || !(FIRST_YEAR..=LAST_YEAR).contains(&date.year()) {
+            return Err(invalid);
+        }
+        Ok(date)
+    }
+}
+
+/// 旧暦の 1 か月。
+#[derive(Debug, Clone, Copy)]
+struct LunarMonth {
+    /// 旧暦の年。
+    year: i32,
+    /// 月の番号。
+    number: u32,
+    /// 閏月かどうか。
+    leap: bool,
+    /// 初日（朔の日）。
+    start: Date,
+    /// 日数（29 または 30）。
+    length: u32,
+}
+
+/// `cycle - 1` 年の冬至を含む月から `cycle` 年の冬至を含む月の前月までの月を返します。
+fn cycle_months(cycle: i32) -> Vec<LunarMonth> {
+    let first = eleventh_month_index(cycle - 1);
+    let last = eleventh_month_index(cycle);
+    let starts: Vec<Date> = (first..=last).map(new_moon_date).collect();
+    let principal_terms: Vec<Date> = [cycle - 1, cycle]
+        .into_iter()
+        .flat_map(|year| {
+            SolarTerm::ALL
+                .into_iter()
+                .filter(|t| t.is_principal())
+                .map(move |t| solar_term_date(t, year))
+        })
+        .collect();
+    let has_principal_term = |i: usize| {
+        principal_terms
+            .iter()
+            .any(|&d| starts[i] <= d && d < starts[i + 1])
+    };
+    // 13 か月ある場合は最初に中気を含まない月を閏月とする。
+    let leap_index = (starts.len() == 14)
+        .then(|| (1..13).find(|&i| !has_principal_term(i)))
+        .flatten();
+
+    let mut months = Vec::with_capacity(13);
+    let mut number = 11;
+    for i in 0..starts.len() - 1 {
+        let leap = Some(i) == leap_index;
+        if i > 0 && !leap {
+            number = number % 12 + 1;
+        }
+        months.push(LunarMonth {
+            year: if number >= 11 { cycle - 1 } else { cycle },
+            number,
+            leap,
+            start: starts[i],
+            length: (starts[i + 1].to_ordinal() - starts[i].to_ordinal()) as u32,
+        });
+    }
+    months
+}
+
+/// `year` 年の冬至を含む月の朔の番号を返します。
+fn eleventh_month_index(year: i32) -> i64 {
+    new_moon_index(solar_term_date(SolarTerm::WinterSolstice, year))
+}
+
+/// `year` 年の節気 `term` の日付を返します。
+///
+/// 旧暦で参照する年（対象の前後 1〜2 年）は常に [`SolarTerm::SUPPORTED_YEARS`] に含まれます。
+fn solar_term_date(term: SolarTerm, year: i32) -> Date {
+    term.in_year(year)
+        .expect("lunisolar years are within the supported solar term years")
+        .date
+}
+
+/// `date` 以前で最も近い朔の番号（2000-01-06 の朔を 0 とする）を返します。
+fn new_moon_index(date: Date) -> i64 {
+    let jd = astronomy::julian_day(
+        date.year() as i64,
+        date.month() as i64,
+        date.day() as i64,
+        0.0,
+    );
+    let mut k = ((jd - 2_451_550.1) / astronomy::SYNODIC_MONTH).floor() as i64;
+    while new_moon_date(k) > date {
+        k -= 1;
+    }
+    while new_moon_date(k + 1) <= date {
+        k += 1;
+    }
+    k
+}
+
+/// `k` 番目の朔の日付（日本標準時）を返します。
+fn new_moon_date(k: i64) -> Date {
+    let jd = astronomy::universal_time(astronomy::new_moon_jde(k as f64));
+    let time = astronomy::julian_day_to_jst(jd);
+    Date::new(time.year, time.month, time.day)
+        .expect("new moon calculation produced an invalid calendar date")
+}
@@ -1,22 +1,245 @@
 //! 祝日を表すモデル。
 
+use crate::checker;
 use crate::date::Date;
+use crate::lineage::HolidayId;
+use crate::locale::{self, Locale};
+use crate::rules::{self, HolidayRule};
 
-/// 1 件の祝日（日付と名称）を表す不変の値。
+/// 組込みの国民の祝日（祝日法および皇室慶弔行事に伴う特別法によるもの）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinHoliday {
+    /// 元日
+    NewYear,
+    /// 成人の日
+    AdultDay,
+    /// 建国記念の日
+    FoundationDay,
+    /// 天皇誕生日
+    EmperorsBirthday,
+    /// 春分の日
+    VernalEquinox,
+    /// みどりの日
+    GreeneryDay,
+    /// 昭和の日
+    ShowaDay,
+    /// 憲法記念日
+    ConstitutionMemorialDay,
+    /// こどもの日
+    ChildrensDay,
+    /// 海の日
+    SeaDay,
+    /// 山の日
+    MountainDay,
+    /// 敬老の日
+    RespectForTheAgedDay,
+    /// 秋分の日
+    AutumnEquinox,
+    /// 体育の日
+    HealthAndSportsDay,
+    /// スポーツの日
+    SportsDay,
+    /// 文化の日
+    CultureDay,
+    /// 勤労感謝の日
+    LaborThanksgivingDay,
+    /// 1959 年 皇太子・明仁親王の結婚の儀
+    ExtraHoliday1959,
+    /// 1989 年 昭和天皇の大喪の礼
+    ExtraHoliday1989,
+    /// 1990 年 即位の礼正殿の儀
+    ExtraHoliday1990,
+    /// 1993 年 皇太子・皇太子徳仁親王の結婚の儀
+    ExtraHoliday1993,
+    /// 2019 年 5 月 1 日 天皇の即位の日
+    ExtraHoliday2019May,
+    /// 2019 年 10 月 22 日 即位礼正殿の儀
+    ExtraHoliday2019Oct,
+}
+
+impl BuiltinHoliday {
+    /// すべての組込み祝日（既定のレジストリ順）。
+    pub const ALL: [BuiltinHoliday; 23] = [
+        BuiltinHoliday::NewYear,
+        BuiltinHoliday::AdultDay,
+        BuiltinHoliday::FoundationDay,
+        BuiltinHoliday::EmperorsBirthday,
+        BuiltinHoliday::VernalEquinox,
+        BuiltinHoliday::GreeneryDay,
+        BuiltinHoliday::ShowaDay,
+        BuiltinHoliday::ConstitutionMemorialDay,
+        BuiltinHoliday::ChildrensDay,
+        BuiltinHoliday::SeaDay,
+        BuiltinHoliday::MountainDay,
+        BuiltinHoliday::RespectForTheAgedDay,
+        BuiltinHoliday::AutumnEquinox,
+        BuiltinHoliday::HealthAndSportsDay,
+        BuiltinHoliday::SportsDay,
+        BuiltinHoliday::CultureDay,
+        BuiltinHoliday::LaborThanksgivingDay,
+        BuiltinHoliday::ExtraHoliday1959,
+        BuiltinHoliday::ExtraHoliday1989,
+        BuiltinHoliday::ExtraHoliday1990,
+        BuiltinHoliday::ExtraHoliday1993,
+        BuiltinHoliday::ExtraHoliday2019May,
+        BuiltinHoliday::ExtraHoliday2019Oct,
+    ];
+
+    /// この祝日の名称を返します（例: `"元日"`）。
+    pub fn name(self) -> &'static str {
+        checker::Builtin::from(self).name()
+    }
+
+    /// この祝日の指定言語での名称を返します（例: 英語なら `"New Year's Day"`）。
+    pub fn localized_name(self, locale: Locale) -> &'static str {
+        locale::builtin_name(self.into(), locale)
+    }
+
+    /// 名称（日本語）から組込み祝日を返します。一致しなければ `None`。
+    pub fn from_name(name: &str) -> Option<BuiltinHoliday> {
+        BuiltinHoliday::ALL.into_iter().find(|b| b.name() == name)
+    }
+
+    /// `year` 年のこの祝日の日付を返します。その年にこの祝日がなければ `None`。
+    ///
+    /// 年内を走査せずに規則から直接求めます。東京オリンピックに伴う 2020・2021 年の特例や、
+    /// 廃止・新設前後の年も反映します（振替休日は含みません）。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{BuiltinHoliday, Date};
+    ///
+    /// assert_eq!(
+    ///     BuiltinHoliday::SeaDay.date_in(2031),
+    ///     Some(Date::new(2031, 7, 21).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     BuiltinHoliday::SeaDay.date_in(2021),
+    ///     Some(Date::new(2021, 7, 22).unwrap())
+    /// );
+    /// assert_eq!(BuiltinHoliday::MountainDay.date_in(2015), None);
+    /// ```
+    pub fn date_in(self, year: i32) -> Option<Date> {
+        checker::Builtin::from(self).date_in(year)
+    }
+
+    /// この祝日の年代ごとの規則（日付の決め方・根拠法令・適用年）を年代順に返します。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::BuiltinHoliday;
+    ///
+    /// let history = BuiltinHoliday::HealthAndSportsDay.rules();
+    /// assert_eq!(history[0].date_rule, "10月10日");
+    /// assert_eq!((history[0].since, history[0].until), (1966, Some(1999)));
+    /// assert_eq!(history[1].date_rule, "10月の第2月曜日");
+    /// assert_eq!(
+    ///     BuiltinHoliday::HealthAndSportsDay.successor(),
+    ///     Some(BuiltinHoliday::SportsDay)
+    /// );
+    /// ```
+    pub fn rules(self) -> &'static [HolidayRule] {
+        rules::rules_of(self.into())
+    }
+
+    /// `year` に適用される規則を返します。その年にこの祝日がなければ `None`。
+    pub fn rule_for(self, year: i32) -> Option<&'static HolidayRule> {
+        rules::rule_for(self.into(), year)
+    }
+
+    /// この祝日が最初に適用された年を返します。
+    pub fn first_year(self) -> i32 {
+        self.rules()[0].since
+    }
+
+    /// この祝日が最後に適用された年を返します（現行の祝日なら `None`）。
+    ///
+    /// 改称された祝日は改称前の最後の年、1 回限りの休日はその年を返します。
+    pub fn last_year(self) -> Option<i32> {
+        self.rules().last().and_then(|r| r.until)
+    }
+
+    /// 改称により後を継いだ祝日を返します。
+    ///
+    /// [`HolidayId::lineage`] でこの祝日の次に並ぶ名称の祝日です
+    /// （体育の日 → スポーツの日、4 月 29 日の天皇誕生日 → みどりの日 → 昭和の日）。
+    /// 1989 年以降の天皇誕生日は別の系譜のため、天皇誕生日の後継はみどりの日になります。
+    pub fn successor(self) -> Option<BuiltinHoliday> {
+        HolidayId::ALL.into_iter().find_map(|id| {
+            let lineage = id.lineage();
+            let index = lineage.iter().position(|n| n.holiday == self)?;
+            lineage.get(index + 1).map(|n| n.holiday)
+        })
+    }
+}
+
+/// 祝日の種別。
+///
+/// 名称文字列を解析せずに、組込みの祝日・振替休日・国民の休日・独自の休日を判別できます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayKind {
+    /// 組込みの国民の祝日。
+    Builtin(BuiltinHoliday),
+    /// 振替休日。
+    Substitute {
+        /// 振替の元となった（日曜日に当たった）祝日。
+        original: BuiltinHoliday,
+        /// 振替の元となった祝日の日付。
+        original_date: Date,
+    },
+    /// 国民の休日（祝日に挟まれた平日）。
+    Citizens,
+    /// 利用者が登録した独自の休日（[`crate::OriginalHolidayChecker`]）。
+    Original,
+}
+
+impl HolidayKind {
+    /// この種別の祝日の指定言語での名称を返します。
+    ///
+    /// 独自の休日（[`HolidayKind::Original`]）の名称は登録したチェッカーが決めるため `None`。
+    pub fn localized_name(self, locale: Locale) -> Option<String> {
+        match self {
+            HolidayKind::Builtin(b) => Some(b.localized_name(locale).to_string()),
+            HolidayKind::Substitute { original, .. } => Some(locale::substitute_name(
+                original.localized_name(locale),
+                locale,
+            )),
+            HolidayKind::Citizens => Some(locale::citizens_name(locale).to_string()),
+            HolidayKind::Original => None,
+        }
+    }
+}
+
+/// 1 件の祝日（日付・名称・種別）を表す不変の値。
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Holiday {
     /// 祝日の日付。
     pub date: Date,
     /// 祝日の名称（例: `"元日"`、`"元日 振替休日"`、`"国民の休日"`）。
     pub name: String,
+    /// 祝日の種別。
+    pub kind: HolidayKind,
 }
 
 impl Holiday {
     /// 日付と名称から祝日を生成します。
+    ///
+    /// 種別は既定の祝日判定から推定します。名称がいずれかの表記言語（[`Locale`]）でその日の
+    /// 組込み祝日（振替休日・国民の休日を含む）と一致すればその種別、一致しなければ
+    /// [`HolidayKind::Original`] になります。種別を指定するには [`Holiday::with_kind`] を
+    /// 使用してください。
     pub fn new(date: Date, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let kind = checker::infer_kind(date, &name);
+        Holiday { date, name, kind }
+    }
+
+    /// 日付・名称・種別を指定して祝日を生成します。
+    pub fn with_kind(date: Date, name: impl Into<String>, kind: HolidayKind) -> Self {
         Holiday {
             date,
             name: name.into(),
+            kind,
         }
     }
 
@@ -0,0 +1,103 @@
+//! 祝日ごとの年をまたいだ発生日の系列。
+//!
+//! 1 つの祝日について、指定した年の範囲の各年の日付・曜日と、日曜日に当たって振替休日を
+//! 生じさせた場合はその振替休日の日付を列挙します。
+
+use crate::checker::{self, Checker};
+use crate::date::Date;
+use crate::lineage::HolidayId;
+use crate::model::BuiltinHoliday;
+use crate::registry::HolidayCheckerRegistry;
+use crate::weekday::Weekday;
+use std::ops::RangeInclusive;
+
+/// ある年の祝日の発生日。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayOccurrence {
+    /// 祝日。
+    pub holiday: BuiltinHoliday,
+    /// 日付。
+    pub date: Date,
+    /// 曜日。
+    pub weekday: Weekday,
+    /// この祝日が日曜日に当たって生じた振替休日の日付（生じなければ `None`）。
+    pub substitute: Option<Date>,
+}
+
+impl BuiltinHoliday {
+    /// `years` の各年のこの祝日の発生日を年順に列挙します（祝日のない年は飛ばします）。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{BuiltinHoliday, Date};
+    ///
+    /// // 憲法記念日が生じさせた振替休日。
+    /// let substitutes: Vec<Date> = BuiltinHoliday::ConstitutionMemorialDay
+    ///     .occurrences(2000..=2030)
+    ///     .filter_map(|o| o.substitute)
+    ///     .collect();
+    /// assert_eq!(substitutes[0], Date::new(2009, 5, 6).unwrap());
+    /// ```
+    pub fn occurrences(
+        self,
+        years: RangeInclusive<i32>,
+    ) -> impl DoubleEndedIterator<Item = HolidayOccurrence> {
+        let registry = HolidayCheckerRegistry::new();
+        years.filter_map(move |year| occurrence(self, year, registry.checkers()))
+    }
+}
+
+impl HolidayId {
+    /// `years` の各年のこの系譜の祝日の発生日を年順に列挙します（改称前後を通して列挙します）。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{BuiltinHoliday, HolidayId};
+    ///
+    /// let names: Vec<BuiltinHoliday> = HolidayId::SportsDay
+    ///     .occurrences(2019..=2020)
+    ///     .map(|o| o.holiday)
+    ///     .collect();
+    /// assert_eq!(names, [BuiltinHoliday::HealthAndSportsDay, BuiltinHoliday::SportsDay]);
+    /// ```
+    pub fn occurrences(
+        self,
+        years: RangeInclusive<i32>,
+    ) -> impl DoubleEndedIterator<Item = HolidayOccurrence> {
+        let registry = HolidayCheckerRegistry::new();
+        years.filter_map(move |year| {
+            let name = self.lineage().iter().find(|n| n.applies_to(year))?;
+            occurrence(name.holiday, year, registry.checkers())
+        })
+    }
+}
+
+/// `year` 年の `holiday` の発生日を求めます。
+fn occurrence(
+    holiday: BuiltinHoliday,
+    year: i32,
+    checkers: &[Checker],
+) -> Option<HolidayOccurrence> {
+    let date = holiday.date_in(year)?;
+    Some(HolidayOccurrence {
+        holiday,
+        date,
+        weekday: date.day_of_week(),
+        substitute: substitute_of(date, checkers),
+    })
+}
+
+/// 祝日 `date` が生じさせた振替休日を、続く祝日をたどって求めます。
+fn substitute_of(date: Date, checkers: &[Checker]) -> Option<Date> {
+    if date.day_of_week() != Weekday::Sunday {
+        return None;
+    }
+    let mut current = date.succ();
+    loop {
+        if let Some((_, original_date)) = checker::transfer_origin(current, checkers) {
+            return (original_date == date).then_some(current);
+        }
+        checker::first_builtin_on(current, checkers)?;
+        current = current.succ();
+    }
+}
@@ -0,0 +1,161 @@
+//! 民法の期間計算（第 140 条〜第 143 条）。
+//!
+//! 初日不算入（第 140 条）、日による期間の満了（第 141 条）、週・月・年による期間の暦による
+//! 計算と応当日（第 143 条）、末日が休日に当たる場合の延長（第 142 条）を扱います。
+//! 休日の判定は [`JPHoliday`] のカレンダー（週末・祝日・登録済みの独自祝日）に従います。
+
+use crate::date::{Date, days_in_month};
+use crate::jpholiday::JPHoliday;
+use std::fmt;
+
+/// 期間の長さ。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    /// 日で定めた期間。
+    Days(u32),
+    /// 週で定めた期間。
+    Weeks(u32),
+    /// 月で定めた期間。
+    Months(u32),
+    /// 年で定めた期間。
+    Years(u32),
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Period::Days(n) => write!(f, "{n}日"),
+            Period::Weeks(n) => write!(f, "{n}週間"),
+            Period::Months(n) => write!(f, "{n}か月"),
+            Period::Years(n) => write!(f, "{n}年"),
+        }
+    }
+}
+
+/// 期間計算の結果。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PeriodDeadline {
+    /// 期間の初日（起算の基準となる出来事の日）。
+    pub start: Date,
+    /// 期間の長さ。
+    pub period: Period,
+    /// 起算日（初日不算入により `start` の翌日）。
+    pub initial_day: Date,
+    /// 期間の末日（休日による延長前）。
+    pub period_end: Date,
+    /// 期間の満了日（末日が休日の場合は延長後の日）。
+    pub deadline: Date,
+    /// 計算の根拠を順に説明した文。
+    pub explanation: Vec<String>,
+}
+
+impl PeriodDeadline {
+    /// 末日が休日に当たり満了日が延長されたかどうかを返します。
+    pub fn is_extended(&self) -> bool {
+        self.deadline != self.period_end
+    }
+}
+
+/// `start` を初日とする `period` の期間の満了日を `calendar` の休日に従って計算します。
+///
+/// 起算日や期間の末日が `Date` の表現できる範囲を超える場合や、独自チェッカーによって
+/// 休日が際限なく続き期限が定まらない場合は `None`。
+pub(crate) fn period_deadline(
+    start: Date,
+    period: Period,
+    calendar: &JPHoliday,
+) -> Option<PeriodDeadline> {
+    let initial_day = checked_add_days(start, 1)?;
+    let mut explanation = vec![format!(
+        "初日不算入（民法第140条）: 期間は翌日 {initial_day} から起算する"
+    )];
+
+    let period_end = match period {
+        Period::Days(n) => {
+            let end = checked_add_days(initial_day, i64::from(n) - 1)?;
+            explanation.push(format!(
+                "日による期間（民法第141条）: {period}目の末日 {end} の終了をもって満了する"
+            ));
+            end
+        }
+        Period::Weeks(n) => {
+            let end = checked_add_days(initial_day, 7 * i64::from(n) - 1)?;
+            explanation.push(format!(
+                "週による期間（民法第143条）: 最後の週の応当日 {} の前日 {end} に満了する",
+                end.succ()
+            ));
+            end
+        }
+        Period::Months(n) => months_end(initial_day, i64::from(n), period, &mut explanation)?,
+        Period::Years(n) => months_end(initial_day, 12 * i64::from(n), period, &mut explanation)?,
+    };
+
+    let deadline = calendar.checked_deadline(period_end)?;
+    if deadline != period_end {
+        let holidays = calendar.holidays(period_end);
+        let reason = if holidays.is_empty() {
+            period_end.day_of_week().japanese_name().to_string()
+        } else {
+            holidays
+                .iter()
+                .map(|h| h.name.as_str())
+                .collect::<Vec<_>>()
+                .join("・")
+        };
+        explanation.push(format!(
+            "末日の休日（民法第142条）: {period_end} は休日（{reason}）のため、翌営業日 {deadline} に満了する"
+        ));
+    }
+
+    Some(PeriodDeadline {
+        start,
+        period,
+        initial_day,
+        period_end,
+        deadline,
+        explanation,
+    })
+}
+
+/// `date` の `days` 日後を返します。`Date` の表現できる範囲を超える場合は `None`。
+fn checked_add_days(date: Date, days: i64) -> Option<Date> {
+    let ordinal = date.to_ordinal().checked_add(days)?;
+    let result = Date::from_ordinal(ordinal);
+    (result.to_ordinal() == ordinal).then_some(result)
+}
+
+/// 起算日 `initial_day` から `months` か月の期間の末日を暦に従って求めます（民法第 143 条）。
+///
+/// 末日の年が `i32` の範囲を超える場合は `None`。
+fn months_end(
+    initial_day: Date,
+    months: i64,
+    period: Period,
+    explanation: &mut Vec<String>,
+) -> Option<Date> {
+    let index = i64::from(initial_day.year()) * 12 + i64::from(initial_day.month()) - 1 + months;
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = index.rem_euclid(12) as u32 + 1;
+    let kind = if matches!(period, Period::Years(_)) {
+        "年による期間"
+    } else {
+        "月による期間"
+    };
+    match Date::new(year, month, initial_day.day()) {
+        Ok(corresponding) => {
+            let end = corresponding.pred();
+            explanation.push(format!(
+                "{kind}（民法第143条）: 最後の月の応当日 {corresponding} の前日 {end} に満了する"
+            ));
+            Some(end)
+        }
+        Err(_) => {
+            let last = days_in_month(year, month).expect("month is always in 1..=12");
+            let end = Date::new(year, month, last).expect("the last day of a month is valid");
+            explanation.push(format!(
+                "{kind}（民法第143条）: 最後の月に応当日がないため、その月の末日 {end} に満了する"
+            ));
+            Some(end)
+        }
+    }
+}
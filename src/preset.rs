@@ -0,0 +1,121 @@
+//! 業界別の休業日カレンダー（プリセット）。
+//!
+//! 国民の祝日に加えて業界ごとに定められた休業日を [`OriginalHolidayChecker`] として提供します。
+//! [`crate::JPHoliday`] やグローバル API に登録すれば、祝日判定・営業日計算にそのまま反映されます。
+
+use crate::checker::OriginalHolidayChecker;
+use crate::date::Date;
+use crate::locale::Locale;
+
+/// 銀行の休業日（12 月 31 日、1 月 2 日、1 月 3 日）。
+///
+/// 銀行法施行令第 5 条で国民の祝日・土曜日・日曜日に加えて定められた年末年始の休業日です。
+/// 1 月 1 日は国民の祝日（元日）のため含みません。[`crate::JPHoliday::bank`] で
+/// 登録済みのインスタンスを生成できます。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{BankHoliday, Date, JPHoliday};
+///
+/// let mut jp = JPHoliday::new();
+/// jp.register(BankHoliday);
+/// assert_eq!(
+///     jp.is_holiday_name(Date::new(2024, 12, 31).unwrap()).as_deref(),
+///     Some("年末休業日")
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BankHoliday;
+
+impl OriginalHolidayChecker for BankHoliday {
+    fn is_holiday(&self, date: Date) -> bool {
+        matches!((date.month(), date.day()), (12, 31) | (1, 2) | (1, 3))
+    }
+
+    fn holiday_name(&self, date: Date) -> String {
+        self.localized_holiday_name(date, Locale::Japanese)
+    }
+
+    fn localized_holiday_name(&self, date: Date, locale: Locale) -> String {
+        year_end_closure_name(date.month() == 12, locale).to_string()
+    }
+}
+
+/// 年末（`year_end`）・年始の休業日の指定言語での名称を返します。
+fn year_end_closure_name(year_end: bool, locale: Locale) -> &'static str {
+    match (year_end, locale) {
+        (true, Locale::Japanese) => "年末休業日",
+        (true, Locale::English) => "Year-End Bank Holiday",
+        (true, Locale::Romaji) => "Nenmatsu Kyugyobi",
+        (true, Locale::Hiragana) => "ねんまつきゅうぎょうび",
+        (false, Locale::Japanese) => "年始休業日",
+        (false, Locale::English) => "New Year Bank Holiday",
+        (false, Locale::Romaji) => "Nenshi Kyugyobi",
+        (false, Locale::Hiragana) => "ねんしきゅうぎょうび",
+    }
+}
+
+/// 東京証券取引所の休業日のうち年末年始（12 月 31 日、1 月 2 日、1 月 3 日）。
+///
+/// 国民の祝日・土曜日・日曜日に加えて休業日とします。1989-02-24（昭和天皇の大喪の礼）の
+/// 休業は国民の祝日として扱われるため、ここには含みません。土曜日の全面休業（1989 年 2 月）
+/// より前の土曜日の立会いは対象外です。2020-10-01 のような臨時の終日売買停止は決済が行われた
+/// 日で休業日ではないため、[`crate::TseCalendar`] が売買日の判定でのみ除外します。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TseHoliday;
+
+impl OriginalHolidayChecker for TseHoliday {
+    fn is_holiday(&self, date: Date) -> bool {
+        matches!((date.month(), date.day()), (12, 31) | (1, 2) | (1, 3))
+    }
+
+    fn holiday_name(&self, date: Date) -> String {
+        self.localized_holiday_name(date, Locale::Japanese)
+    }
+
+    fn localized_holiday_name(&self, _date: Date, locale: Locale) -> String {
+        year_end_market_closure_name(locale).to_string()
+    }
+}
+
+/// 取引所の年末年始休業日の指定言語での名称を返します。
+fn year_end_market_closure_name(locale: Locale) -> &'static str {
+    match locale {
+        Locale::Japanese => "年末年始休業日",
+        Locale::English => "Year-End Market Holiday",
+        Locale::Romaji => "Nenmatsu Nenshi Kyugyobi",
+        Locale::Hiragana => "ねんまつねんしきゅうぎょうび",
+    }
+}
+
+/// 行政機関の休日のうち年末年始（12 月 29 日〜1 月 3 日）。
+///
+/// 行政機関の休日に関する法律第 1 条で土曜日・日曜日・国民の祝日に加えて定められた休日です。
+/// 1 月 1 日は国民の祝日（元日）のため含みません。[`crate::JPHoliday::government`] で
+/// 登録済みのインスタンスを生成でき、[`crate::JPHoliday::deadline`] で提出期限を計算できます。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GovernmentHoliday;
+
+impl OriginalHolidayChecker for GovernmentHoliday {
+    fn is_holiday(&self, date: Date) -> bool {
+        match date.month() {
+            12 => date.day() >= 29,
+            1 => (2..=3).contains(&date.day()),
+            _ => false,
+        }
+    }
+
+    fn holiday_name(&self, date: Date) -> String {
+        self.localized_holiday_name(date, Locale::Japanese)
+    }
+
+    fn localized_holiday_name(&self, _date: Date, locale: Locale) -> String {
+        match locale {
+            Locale::Japanese => "年末年始の休日",
+            Locale::English => "Year-End and New Year Holiday",
+            Locale::Romaji => "Nenmatsu Nenshi no Kyujitsu",
+            Locale::Hiragana => "ねんまつねんしのきゅうじつ",
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,281 @@
+//! 組込み祝日の日付の決め方と法的根拠の一覧。
+//!
+//! 各祝日について、年代ごとの日付の規則（例: `"1月の第2月曜日"`）・根拠となる法令・適用年を
+//! 保持します。適用年は [`Builtin::is_holiday`] がその規則で祝日と判定する年と一致させています。
+//! 改称された祝日の系譜（[`crate::HolidayId::lineage`]）の名称の期間もこの表から求めます。
+
+use crate::checker::Builtin;
+
+/// 祝日法（国民の祝日に関する法律、昭和23年法律第178号）。
+const ACT: &str = "国民の祝日に関する法律（昭和23年法律第178号）";
+/// 建国記念の日・敬老の日・体育の日を新設した改正。
+const ACT_1966: &str = "国民の祝日に関する法律の一部を改正する法律（昭和41年法律第86号）";
+/// 天皇誕生日の変更とみどりの日の新設。
+const ACT_1989: &str = "国民の祝日に関する法律の一部を改正する法律（平成元年法律第5号）";
+/// 海の日の新設。
+const ACT_1995: &str = "国民の祝日に関する法律の一部を改正する法律（平成7年法律第22号）";
+/// 成人の日・体育の日の月曜日化（ハッピーマンデー制度）。
+const ACT_1998: &str = "国民の祝日に関する法律の一部を改正する法律（平成10年法律第141号）";
+/// 海の日・敬老の日の月曜日化。
+const ACT_2001: &str = "国民の祝日に関する法律の一部を改正する法律（平成13年法律第59号）";
+/// 昭和の日の新設とみどりの日の移動。
+const ACT_2005: &str = "国民の祝日に関する法律の一部を改正する法律（平成17年法律第43号）";
+/// 山の日の新設。
+const ACT_2014: &str = "国民の祝日に関する法律の一部を改正する法律（平成26年法律第43号）";
+/// 天皇誕生日の変更（天皇の退位に伴う改正）。
+const ACT_2017: &str = "天皇の退位等に関する皇室典範特例法（平成29年法律第63号）附則第10条";
+/// 体育の日のスポーツの日への改称。
+const ACT_2018: &str = "国民の祝日に関する法律の一部を改正する法律（平成30年法律第57号）";
+/// 2020 年の海の日・スポーツの日・山の日の特例。
+const OLYMPIC_2020: &str = "平成32年東京オリンピック競技大会・東京パラリンピック競技大会特別措置法（平成27年法律第33号、平成30年法律第55号による改正）";
+/// 2021 年の海の日・スポーツの日・山の日の特例。
+const OLYMPIC_2021: &str = "平成32年東京オリンピック競技大会・東京パラリンピック競技大会特別措置法等の一部を改正する法律（令和2年法律第68号）";
+
+/// 組込み祝日の 1 つの年代の規則。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayRule {
+    /// 日付の決め方（例: `"1月の第2月曜日"`）。
+    pub date_rule: &'static str,
+    /// 根拠となる法令。
+    pub legal_basis: &'static str,
+    /// 規則を適用する最初の年。
+    pub since: i32,
+    /// 規則を適用する最後の年（現行の規則なら `None`）。
+    pub until: Option<i32>,
+}
+
+impl HolidayRule {
+    /// `year` がこの規則の適用年に含まれるかどうかを返します。
+    pub fn applies_to(&self, year: i32) -> bool {
+        self.since <= year && self.until.is_none_or(|until| year <= until)
+    }
+}
+
+/// 規則を簡潔に記述するための補助関数。
+const fn rule(
+    date_rule: &'static str,
+    legal_basis: &'static str,
+    since: i32,
+    until: Option<i32>,
+) -> HolidayRule {
+    HolidayRule {
+        date_rule,
+        legal_basis,
+        since,
+        until,
+    }
+}
+
+/// 組込み祝日の規則を年代順に返します。
+pub(crate) const fn rules_of(b: Builtin) -> &'static [HolidayRule] {
+    match b {
+        Builtin::NewYear => const { &[rule("1月1日", ACT, 1949, None)] },
+        Builtin::AdultDay => {
+            const {
+                &[
+                    rule("1月15日", ACT, 1949, Some(1999)),
+                    rule("1月の第2月曜日", ACT_1998, 2000, None),
+                ]
+            }
+        }
+        Builtin::FoundationDay => const { &[rule("2月11日", ACT_1966, 1967, None)] },
+        Builtin::EmperorsBirthday => {
+            const {
+                &[
+                    rule("4月29日", ACT, 1949, Some(1988)),
+                    rule("12月23日", ACT_1989, 1989, Some(2018)),
+                    rule("2月23日", ACT_2017, 2020, None),
+                ]
+            }
+        }
+        Builtin::VernalEquinox => {
+            const { &[rule("春分日（天文計算による）", ACT, 1949, None)] }
+        }
+        Builtin::GreeneryDay => {
+            const {
+                &[
+                    rule("4月29日", ACT_1989, 1989, Some(2006)),
+                    rule("5月4日", ACT_2005, 2007, None),
+                ]
+            }
+        }
+        Builtin::ShowaDay => const { &[rule("4月29日", ACT_2005, 2007, None)] },
+        Builtin::ConstitutionMemorialDay => const { &[rule("5月3日", ACT, 1949, None)] },
+        Builtin::ChildrensDay => const { &[rule("5月5日", ACT, 1949, None)] },
+        Builtin::SeaDay => {
+            const {
+                &[
+                    rule("7月20日", ACT_1995, 1996, Some(2002)),
+                    rule("7月の第3月曜日", ACT_2001, 2003, Some(2019)),
+                    rule(
+                        "7月23日（東京オリンピックによる特例）",
+                        OLYMPIC_2020,
+                        2020,
+                        Some(2020),
+                    ),
+                    rule(
+                        "7月22日（東京オリンピックによる特例）",
+                        OLYMPIC_2021,
+                        2021,
+                        Some(2021),
+                    ),
+                    rule("7月の第3月曜日", ACT_2001, 2022, None),
+                ]
+            }
+        }
+        Builtin::MountainDay => {
+            const {
+                &[
+                    rule("8月11日", ACT_2014, 2016, Some(2019)),
+                    rule(
+                        "8月10日（東京オリンピックによる特例）",
+                        OLYMPIC_2020,
+                        2020,
+                        Some(2020),
+                    ),
+                    rule(
+                        "8月8日（東京オリンピックによる特例）",
+                        OLYMPIC_2021,
+                        2021,
+                        Some(2021),
+                    ),
+                    rule("8月11日", ACT_2014, 2022, None),
+                ]
+            }
+        }
+        Builtin::RespectForTheAgedDay => {
+            const {
+                &[
+                    rule("9月15日", ACT_1966, 1966, Some(2002)),
+                    rule("9月の第3月曜日", ACT_2001, 2003, None),
+                ]
+            }
+        }
+        Builtin::AutumnEquinox => {
+            const { &[rule("秋分日（天文計算による）", ACT, 1948, None)] }
+        }
+        Builtin::HealthAndSportsDay => {
+            const {
+                &[
+                    rule("10月10日", ACT_1966, 1966, Some(1999)),
+                    rule("10月の第2月曜日", ACT_1998, 2000, Some(2019)),
+                ]
+            }
+        }
+        Builtin::SportsDay => {
+            const {
+                &[
+                    rule(
+                        "7月24日（東京オリンピックによる特例）",
+                        OLYMPIC_2020,
+                        2020,
+                        Some(2020),
+                    ),
+                    rule(
+                        "7月23日（東京オリンピックによる特例）",
+                        OLYMPIC_2021,
+                        2021,
+                        Some(2021),
+                    ),
+                    rule("10月の第2月曜日", ACT_2018, 2022, None),
+                ]
+            }
+        }
+        Builtin::CultureDay => const { &[rule("11月3日", ACT, 1948, None)] },
+        Builtin::LaborThanksgivingDay => const { &[rule("11月23日", ACT, 1948, None)] },
+        Builtin::ExtraHoliday1959 => {
+            const {
+                &[rule(
+                    "1959年4月10日",
+                    "皇太子明仁親王の結婚の儀の行われる日を休日とする法律（昭和34年法律第16号）",
+                    1959,
+                    Some(1959),
+                )]
+            }
+        }
+        Builtin::ExtraHoliday1989 => {
+            const {
+                &[rule(
+                    "1989年2月24日",
+                    "昭和天皇の大喪の礼の行われる日を休日とする法律（平成元年法律第4号）",
+                    1989,
+                    Some(1989),
+                )]
+            }
+        }
+        Builtin::ExtraHoliday1990 => {
+            const {
+                &[rule(
+                    "1990年11月12日",
+                    "即位礼正殿の儀の行われる日を休日とする法律（平成2年法律第24号）",
+                    1990,
+                    Some(1990),
+                )]
+            }
+        }
+        Builtin::ExtraHoliday1993 => {
+            const {
+                &[rule(
+                    "1993年6月9日",
+                    "皇太子徳仁親王の結婚の儀の行われる日を休日とする法律（平成5年法律第32号）",
+                    1993,
+                    Some(1993),
+                )]
+            }
+        }
+        Builtin::ExtraHoliday2019May => {
+            const {
+                &[rule(
+                    "2019年5月1日",
+                    "天皇の即位の日及び即位礼正殿の儀の行われる日を休日とする法律（平成30年法律第99号）",
+                    2019,
+                    Some(2019),
+                )]
+            }
+        }
+        Builtin::ExtraHoliday2019Oct => {
+            const {
+                &[rule(
+                    "2019年10月22日",
+                    "天皇の即位の日及び即位礼正殿の儀の行われる日を休日とする法律（平成30年法律第99号）",
+                    2019,
+                    Some(2019),
+                )]
+            }
+        }
+    }
+}
+
+/// `year` に適用される組込み祝日の規則を返します（その年に祝日がなければ `None`）。
+pub(crate) fn rule_for(b: Builtin, year: i32) -> Option<&'static HolidayRule> {
+    rules_of(b).iter().find(|r| r.applies_to(year))
+}
+
+/// 振替休日の根拠となる規定を返します。
+pub(crate) fn substitute_legal_basis(year: i32) -> &'static str {
+    if year >= 2007 {
+        "国民の祝日に関する法律第3条第2項（平成17年法律第43号による改正後: 日曜日に当たる祝日の後の最も近い祝日でない日）"
+    } else {
+        "国民の祝日に関する法律第3条第2項（昭和48年法律第10号による追加: 日曜日に当たる祝日の翌日）"
+    }
+}
+
+/// 国民の休日の根拠となる規定。
+pub(crate) const CITIZENS_LEGAL_BASIS: &str =
+    "国民の祝日に関する法律第3条第3項（昭和60年法律第103号による追加: 前日と翌日が祝日である日）";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_cover_exactly_the_years_with_holidays() {
+        for b in Builtin::ALL {
+            for year in 1900..=2100 {
+                let matching = rules_of(b).iter().filter(|r| r.applies_to(year)).count();
+                let expected = usize::from(b.date_in(year).is_some());
+                assert_eq!(matching, expected, "{b:?} in {year}");
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! 前後の祝日の探索。
+//!
+//! 1 日ずつ判定するのではなく、組込み祝日の日付を年単位で直接求め（[`Builtin::date_in`]）、
+//! 振替休日・国民の休日が発生し得る前後の日だけを候補として判定します。独自チェッカーは
+//! 日付を列挙できないため、登録されている場合に限り「次の候補日まで」を 1 日ずつ判定します。
+//! 過去へ遡る探索では、独自の休日を 1 日ずつ判定するのは起点から
+//! [`MAX_CONSECUTIVE_CLOSED_DAYS`] 日前までとします。
+
+use crate::business::MAX_CONSECUTIVE_CLOSED_DAYS;
+use crate::checker::{Builtin, Checker};
+use crate::date::Date;
+use crate::model::Holiday;
+
+/// 組込み祝日が 1 つも存在しない最後の年（祝日法の施行は 1948 年）。
+const LAST_YEAR_WITHOUT_BUILTINS: i32 = 1947;
+
+/// 独自チェッカーが登録されているかどうかを返します。
+fn has_original(checkers: &[Checker]) -> bool {
+    checkers.iter().any(|c| matches!(c, Checker::Original(_)))
+}
+
+/// `year` 内で組込み祝日・振替休日・国民の休日になり得る日を昇順で返します。
+///
+/// 振替休日は日曜日の祝日から連続する祝日の直後（最長で 3 日後）、国民の休日は翌日が組込み
+/// 祝日である日（祝日の前日）にしか発生しないため、各組込み祝日の前日〜3 日後を候補とします。
+/// 年をまたぐ候補を拾うため前後の年の祝日も対象にします（`i32` の端では範囲内の年だけ）。
+fn candidates(year: i32) -> Vec<Date> {
+    let mut out: Vec<Date> = (year.saturating_sub(1)..=year.saturating_add(1))
+        .flat_map(|y| Builtin::ALL.iter().filter_map(move |b| b.date_in(y)))
+        .flat_map(|date| (-1..=3).map(move |offset| date.add_days(offset)))
+        .filter(|date| date.year() == year)
+        .collect();
+    out.sort();
+    out.dedup();
+    out
+}
+
+/// `after` より後で最初に祝日となる日の、すべての祝日を返します。
+///
+/// `holidays_on` はその日に該当する祝日（レジストリ順）を返す関数です。
+pub(crate) fn next_holidays(
+    after: Date,
+    checkers: &[Checker],
+    holidays_on: impl Fn(Date) -> Vec<Holiday>,
+) -> Option<Vec<Holiday>> {
+    if after == last_date() {
+        return None;
+    }
+    first_holidays_from(after.succ(), checkers, holidays_on)
+}
+
+/// `from` 以降（`from` を含む）で最初に祝日となる日の、すべての祝日を返します。
+pub(crate) fn first_holidays_from(
+    mut from: Date,
+    checkers: &[Checker],
+    holidays_on: impl Fn(Date) -> Vec<Holiday>,
+) -> Option<Vec<Holiday>> {
+    let scan_originals = has_original(checkers);
+    loop {
+        let year = from.year();
+        if !scan_originals && year <= LAST_YEAR_WITHOUT_BUILTINS {
+            // 祝日法の施行前には組込み祝日がないため、施行年まで年単位の走査を省く。
+            from = Date::new(LAST_YEAR_WITHOUT_BUILTINS + 1, 1, 1)
+                .expect("January 1st is always a valid date");
+            continue;
+        }
+        let year_end = Date::new(year, 12, 31).expect("December 31st is always a valid date");
+        let builtin = candidates(year)
+            .into_iter()
+            .filter(|date| *date >= from)
+            .map(&holidays_on)
+            .find(|holidays| !holidays.is_empty());
+        if scan_originals {
+            let scan_end = builtin.as_ref().map_or(year_end, |h| h[0].date.pred());
+            let mut date = from;
+            while date <= scan_end {
+                let holidays = holidays_on(date);
+                if !holidays.is_empty() {
+                    return Some(holidays);
+                }
+                date = date.succ();
+            }
+        }
+        if builtin.is_some() {
+            return builtin;
+        }
+        if year == i32::MAX {
+            return None;
+        }
+        from = year_end.succ();
+    }
+}
+
+/// `before` より前で最後に祝日となる日の、すべての祝日を返します。
+///
+/// 独自チェッカーが登録されていなければ祝日法の施行前に達した時点で、登録されていれば
+/// 組込み祝日のない年で `before` の [`MAX_CONSECUTIVE_CLOSED_DAYS`] 日前（1 年 1 月 1 日より
+/// 前には遡らない）に達した時点で探索を打ち切ります。
+pub(crate) fn previous_holidays(
+    before: Date,
+    checkers: &[Checker],
+    holidays_on: impl Fn(Date) -> Vec<Holiday>,
+) -> Option<Vec<Holiday>> {
+    if before.year() < 1 {
+        return None;
+    }
+    last_holidays_until(before.pred(), checkers, holidays_on)
+}
+
+/// `from` 以前（`from` を含む）で最後に祝日となる日の、すべての祝日を返します。
+pub(crate) fn last_holidays_until(
+    mut from: Date,
+    checkers: &[Checker],
+    holidays_on: impl Fn(Date) -> Vec<Holiday>,
+) -> Option<Vec<Holiday>> {
+    let scan_originals = has_original(checkers);
+    // 独自の休日は列挙できないため、1 日ずつ遡って判定するのはこの日までとする。
+    let floor = from.add_days(-i64::from(MAX_CONSECUTIVE_CLOSED_DAYS));
+    loop {
+        let year = from.year();
+        if year < 1 || (year <= LAST_YEAR_WITHOUT_BUILTINS && (!scan_originals || from < floor)) {
+            return None;
+        }
+        let year_start = Date::new(year, 1, 1).expect("January 1st is always a valid date");
+        let builtin = candidates(year)
+            .into_iter()
+            .rev()
+            .filter(|date| *date <= from)
+            .map(&holidays_on)
+            .find(|holidays| !holidays.is_empty());
+        if scan_originals {
+            let scan_end = builtin
+                .as_ref()
+                .map_or(year_start, |h| h[0].date.succ())
+                .max(floor);
+            let mut date = from;
+            while date >= scan_end {
+                let holidays = holidays_on(date);
+                if !holidays.is_empty() {
+                    return Some(holidays);
+                }
+                date = date.pred();
+            }
+        }
+        if builtin.is_some() {
+            return builtin;
+        }
+        from = year_start.pred();
+    }
+}
+
+/// `Date` が表現できる最初の日。
+pub(crate) fn first_date() -> Date {
+    Date::new(i32::MIN, 1, 1).expect("January 1st is always a valid date")
+}
+
+/// `Date` が表現できる最後の日。
+pub(crate) fn last_date() -> Date {
+    Date::new(i32::MAX, 12, 31).expect("December 31st is always a valid date")
+}
@@ -0,0 +1,153 @@
+//! 雑節（節分・彼岸・土用・八十八夜・入梅・半夏生・二百十日・二百二十日）。
+//!
+//! 二十四節気（[`SolarTerm`]）からの日数、または太陽の視黄経が定められた角度に達する日で
+//! 求めます（入梅 80°、半夏生 100°、土用の入り 297°・27°・117°・207°）。対象の年は
+//! 二十四節気と同じ [`SolarTerm::SUPPORTED_YEARS`] です。
+
+use crate::date::Date;
+use crate::solar_term::{self, SolarTerm};
+use std::fmt;
+
+/// 雑節。暦年内のおおよその順に並びます。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, SeasonalDay};
+///
+/// assert_eq!(SeasonalDay::Setsubun.in_year(2025), Some(Date::new(2025, 2, 2).unwrap()));
+/// assert_eq!(SeasonalDay::Hachijuhachiya.in_year(2024), Some(Date::new(2024, 5, 1).unwrap()));
+/// assert_eq!(SeasonalDay::Setsubun.in_year(1500), None);
+/// // 夏の土用は立秋の前日まで。
+/// assert_eq!(
+///     SeasonalDay::SummerDoyo.period_in(2024),
+///     Some((Date::new(2024, 7, 19).unwrap(), Date::new(2024, 8, 6).unwrap()))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SeasonalDay {
+    /// 冬の土用入り（黄経 297°、立春の前日まで）
+    WinterDoyo,
+    /// 節分（立春の前日）
+    Setsubun,
+    /// 春の彼岸入り（春分の 3 日前）
+    SpringHiganStart,
+    /// 春の彼岸明け（春分の 3 日後）
+    SpringHiganEnd,
+    /// 春の土用入り（黄経 27°、立夏の前日まで）
+    SpringDoyo,
+    /// 八十八夜（立春から数えて 88 日目）
+    Hachijuhachiya,
+    /// 入梅（黄経 80°）
+    Nyubai,
+    /// 半夏生（黄経 100°）
+    Hangesho,
+    /// 夏の土用入り（黄経 117°、立秋の前日まで）
+    SummerDoyo,
+    /// 二百十日（立春から数えて 210 日目）
+    Nihyakutoka,
+    /// 二百二十日（立春から数えて 220 日目）
+    Nihyakuhatsuka,
+    /// 秋の彼岸入り（秋分の 3 日前）
+    AutumnHiganStart,
+    /// 秋の彼岸明け（秋分の 3 日後）
+    AutumnHiganEnd,
+    /// 秋の土用入り（黄経 207°、立冬の前日まで）
+    AutumnDoyo,
+}
+
+impl SeasonalDay {
+    /// すべての雑節（暦年内のおおよその順）。
+    pub const ALL: [SeasonalDay; 14] = [
+        SeasonalDay::WinterDoyo,
+        SeasonalDay::Setsubun,
+        SeasonalDay::SpringHiganStart,
+        SeasonalDay::SpringHiganEnd,
+        SeasonalDay::SpringDoyo,
+        SeasonalDay::Hachijuhachiya,
+        SeasonalDay::Nyubai,
+        SeasonalDay::Hangesho,
+        SeasonalDay::SummerDoyo,
+        SeasonalDay::Nihyakutoka,
+        SeasonalDay::Nihyakuhatsuka,
+        SeasonalDay::AutumnHiganStart,
+        SeasonalDay::AutumnHiganEnd,
+        SeasonalDay::AutumnDoyo,
+    ];
+
+    /// 名称（例: `"八十八夜"`）を返します。
+    pub fn name(self) -> &'static str {
+        match self {
+            SeasonalDay::WinterDoyo => "冬の土用入り",
+            SeasonalDay::Setsubun => "節分",
+            SeasonalDay::SpringHiganStart => "春の彼岸入り",
+            SeasonalDay::SpringHiganEnd => "春の彼岸明け",
+            SeasonalDay::SpringDoyo => "春の土用入り",
+            SeasonalDay::Hachijuhachiya => "八十八夜",
+            SeasonalDay::Nyubai => "入梅",
+            SeasonalDay::Hangesho => "半夏生",
+            SeasonalDay::SummerDoyo => "夏の土用入り",
+            SeasonalDay::Nihyakutoka => "二百十日",
+            SeasonalDay::Nihyakuhatsuka => "二百二十日",
+            SeasonalDay::AutumnHiganStart => "秋の彼岸入り",
+            SeasonalDay::AutumnHiganEnd => "秋の彼岸明け",
+            SeasonalDay::AutumnDoyo => "秋の土用入り",
+        }
+    }
+
+    /// 名称から雑節を返します（該当しなければ `None`）。
+    pub fn from_name(name: &str) -> Option<SeasonalDay> {
+        SeasonalDay::ALL.into_iter().find(|s| s.name() == name)
+    }
+
+    /// `year` 年のこの雑節の日付（日本標準時）を返します。
+    ///
+    /// `year` が [`SolarTerm::SUPPORTED_YEARS`] の範囲外なら `None`。
+    pub fn in_year(self, year: i32) -> Option<Date> {
+        let term = |t: SolarTerm| t.in_year(year).map(|t| t.date);
+        let longitude = |lon: f64, month: i64, day: i64| {
+            solar_term::reach_longitude(year, lon, month, day).map(|(date, _)| date)
+        };
+        let date = match self {
+            SeasonalDay::WinterDoyo => longitude(297.0, 1, 17)?,
+            SeasonalDay::Setsubun => term(SolarTerm::StartOfSpring)?.pred(),
+            SeasonalDay::SpringHiganStart => term(SolarTerm::VernalEquinox)?.add_days(-3),
+            SeasonalDay::SpringHiganEnd => term(SolarTerm::VernalEquinox)?.add_days(3),
+            SeasonalDay::SpringDoyo => longitude(27.0, 4, 17)?,
+            SeasonalDay::Hachijuhachiya => term(SolarTerm::StartOfSpring)?.add_days(87),
+            SeasonalDay::Nyubai => longitude(80.0, 6, 11)?,
+            SeasonalDay::Hangesho => longitude(100.0, 7, 2)?,
+            SeasonalDay::SummerDoyo => longitude(117.0, 7, 20)?,
+            SeasonalDay::Nihyakutoka => term(SolarTerm::StartOfSpring)?.add_days(209),
+            SeasonalDay::Nihyakuhatsuka => term(SolarTerm::StartOfSpring)?.add_days(219),
+            SeasonalDay::AutumnHiganStart => term(SolarTerm::AutumnEquinox)?.add_days(-3),
+            SeasonalDay::AutumnHiganEnd => term(SolarTerm::AutumnEquinox)?.add_days(3),
+            SeasonalDay::AutumnDoyo => longitude(207.0, 10, 20)?,
+        };
+        Some(date)
+    }
+
+    /// 彼岸入り・土用入りについて、`year` 年のその期間の初日と最終日を返します。
+    ///
+    /// 彼岸は入りから明けまでの 7 日間、土用は入りから次の立春・立夏・立秋・立冬の前日まで
+    /// です。期間を持たない雑節（彼岸明けを含む）や、`year` が
+    /// [`SolarTerm::SUPPORTED_YEARS`] の範囲外の場合は `None`。
+    pub fn period_in(self, year: i32) -> Option<(Date, Date)> {
+        let day_before = |t: SolarTerm| t.in_year(year).map(|t| t.date.pred());
+        let end = match self {
+            SeasonalDay::SpringHiganStart => SeasonalDay::SpringHiganEnd.in_year(year)?,
+            SeasonalDay::AutumnHiganStart => SeasonalDay::AutumnHiganEnd.in_year(year)?,
+            SeasonalDay::WinterDoyo => SeasonalDay::Setsubun.in_year(year)?,
+            SeasonalDay::SpringDoyo => day_before(SolarTerm::StartOfSummer)?,
+            SeasonalDay::SummerDoyo => day_before(SolarTerm::StartOfAutumn)?,
+            SeasonalDay::AutumnDoyo => day_before(SolarTerm::StartOfWinter)?,
+            _ => return None,
+        };
+        Some((self.in_year(year)?, end))
+    }
+}
+
+impl fmt::Display for SeasonalDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
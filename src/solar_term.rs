@@ -0,0 +1,218 @@
+//! 二十四節気。
+//!
+//! 太陽の視黄経が 15° の倍数に達する瞬刻を [`astronomy`](crate::astronomy) の Newton 法で求め、
+//! 日本標準時の日付と日時を返します。精度は春分・秋分と同じく 1948〜3000 年で ±1 日で、
+//! 瞬刻は簡略式による計算のため数分程度の誤差を含みます。
+//!
+//! 対象は [`SolarTerm::SUPPORTED_YEARS`]（1873〜3000 年）です。日本がグレゴリオ暦を採用した
+//! 1873 年より前（1582 年以前のユリウス暦の時代を含む）や、近似式の範囲を超える年は扱いません。
+
+use crate::astronomy::{self, JulianDateTime};
+use crate::date::Date;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// 二十四節気。暦年内の順（小寒・大寒・立春…冬至）に並びます。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, SolarTerm};
+///
+/// let risshun = SolarTerm::StartOfSpring.in_year(2024).unwrap();
+/// assert_eq!(risshun.date, Date::new(2024, 2, 4).unwrap());
+/// assert_eq!(risshun.time.hour, 17);
+/// assert_eq!(SolarTerm::StartOfSpring.in_year(1500), None);
+/// assert_eq!(SolarTerm::StartOfSpring.to_string(), "立春");
+/// assert_eq!(SolarTerm::StartOfSpring.longitude(), 315);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SolarTerm {
+    /// 小寒（黄経 285°）
+    MinorCold,
+    /// 大寒（黄経 300°）
+    MajorCold,
+    /// 立春（黄経 315°）
+    StartOfSpring,
+    /// 雨水（黄経 330°）
+    RainWater,
+    /// 啓蟄（黄経 345°）
+    AwakeningOfInsects,
+    /// 春分（黄経 0°）
+    VernalEquinox,
+    /// 清明（黄経 15°）
+    ClearAndBright,
+    /// 穀雨（黄経 30°）
+    GrainRain,
+    /// 立夏（黄経 45°）
+    StartOfSummer,
+    /// 小満（黄経 60°）
+    GrainBuds,
+    /// 芒種（黄経 75°）
+    GrainInEar,
+    /// 夏至（黄経 90°）
+    SummerSolstice,
+    /// 小暑（黄経 105°）
+    MinorHeat,
+    /// 大暑（黄経 120°）
+    MajorHeat,
+    /// 立秋（黄経 135°）
+    StartOfAutumn,
+    /// 処暑（黄経 150°）
+    EndOfHeat,
+    /// 白露（黄経 165°）
+    WhiteDew,
+    /// 秋分（黄経 180°）
+    AutumnEquinox,
+    /// 寒露（黄経 195°）
+    ColdDew,
+    /// 霜降（黄経 210°）
+    FrostDescent,
+    /// 立冬（黄経 225°）
+    StartOfWinter,
+    /// 小雪（黄経 240°）
+    MinorSnow,
+    /// 大雪（黄経 255°）
+    MajorSnow,
+    /// 冬至（黄経 270°）
+    WinterSolstice,
+}
+
+/// ある年の二十四節気の日付と瞬刻。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolarTermDate {
+    /// 節気。
+    pub term: SolarTerm,
+    /// 日本標準時での日付。
+    pub date: Date,
+    /// 日本標準時での瞬刻。
+    pub time: JulianDateTime,
+}
+
+impl SolarTerm {
+    /// 計算の対象とする年（日本がグレゴリオ暦を採用した 1873 年から 3000 年まで）。
+    pub const SUPPORTED_YEARS: RangeInclusive<i32> = 1873..=3000;
+
+    /// すべての節気（暦年内の順）。
+    pub const ALL: [SolarTerm; 24] = [
+        SolarTerm::MinorCold,
+        SolarTerm::MajorCold,
+        SolarTerm::StartOfSpring,
+        SolarTerm::RainWater,
+        SolarTerm::AwakeningOfInsects,
+        SolarTerm::VernalEquinox,
+        SolarTerm::ClearAndBright,
+        SolarTerm::GrainRain,
+        SolarTerm::StartOfSummer,
+        SolarTerm::GrainBuds,
+        SolarTerm::GrainInEar,
+        SolarTerm::SummerSolstice,
+        SolarTerm::MinorHeat,
+        SolarTerm::MajorHeat,
+        SolarTerm::StartOfAutumn,
+        SolarTerm::EndOfHeat,
+        SolarTerm::WhiteDew,
+        SolarTerm::AutumnEquinox,
+        SolarTerm::ColdDew,
+        SolarTerm::FrostDescent,
+        SolarTerm::StartOfWinter,
+        SolarTerm::MinorSnow,
+        SolarTerm::MajorSnow,
+        SolarTerm::WinterSolstice,
+    ];
+
+    /// 名称（例: `"立春"`）を返します。
+    pub fn name(self) -> &'static str {
+        match self {
+            SolarTerm::MinorCold => "小寒",
+            SolarTerm::MajorCold => "大寒",
+            SolarTerm::StartOfSpring => "立春",
+            SolarTerm::RainWater => "雨水",
+            SolarTerm::AwakeningOfInsects => "啓蟄",
+            SolarTerm::VernalEquinox => "春分",
+            SolarTerm::ClearAndBright => "清明",
+            SolarTerm::GrainRain => "穀雨",
+            SolarTerm::StartOfSummer => "立夏",
+            SolarTerm::GrainBuds => "小満",
+            SolarTerm::GrainInEar => "芒種",
+            SolarTerm::SummerSolstice => "夏至",
+            SolarTerm::MinorHeat => "小暑",
+            SolarTerm::MajorHeat => "大暑",
+            SolarTerm::StartOfAutumn => "立秋",
+            SolarTerm::EndOfHeat => "処暑",
+            SolarTerm::WhiteDew => "白露",
+            SolarTerm::AutumnEquinox => "秋分",
+            SolarTerm::ColdDew => "寒露",
+            SolarTerm::FrostDescent => "霜降",
+            SolarTerm::StartOfWinter => "立冬",
+            SolarTerm::MinorSnow => "小雪",
+            SolarTerm::MajorSnow => "大雪",
+            SolarTerm::WinterSolstice => "冬至",
+        }
+    }
+
+    /// 名称から節気を返します（該当しなければ `None`）。
+    pub fn from_name(name: &str) -> Option<SolarTerm> {
+        SolarTerm::ALL.into_iter().find(|t| t.name() == name)
+    }
+
+    /// 節気となる太陽の視黄経（度、0〜345 の 15 の倍数）を返します。
+    pub fn longitude(self) -> u32 {
+        (285 + 15 * self as u32) % 360
+    }
+
+    /// 中気（黄経が 30° の倍数の節気。雨水・春分・穀雨…大寒）かどうかを返します。
+    pub fn is_principal(self) -> bool {
+        self.longitude().is_multiple_of(30)
+    }
+
+    /// `year` 年のこの節気の日付と瞬刻（日本標準時）を返します。
+    ///
+    /// `year` が [`SolarTerm::SUPPORTED_YEARS`] の範囲外なら `None`。
+    pub fn in_year(self, year: i32) -> Option<SolarTermDate> {
+        let (month, day) = self.approximate_date();
+        let (date, time) = reach_longitude(year, self.longitude() as f64, month, day)?;
+        Some(SolarTermDate {
+            term: self,
+            date,
+            time,
+        })
+    }
+
+    /// Newton 法の初期値とする、例年の節気のおおよその日付を返します。
+    fn approximate_date(self) -> (i64, i64) {
+        let month = self as i64 / 2 + 1;
+        let day = if self as i64 % 2 == 0 { 6 } else { 21 };
+        (month, day)
+    }
+}
+
+impl fmt::Display for SolarTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// `year` 年の `month` 月 `day` 日ごろに太陽の視黄経が `longitude` 度に達する日付と瞬刻
+/// （日本標準時）を返します。瞬刻は ΔT を差し引いた世界時から求めます。
+///
+/// `year` が [`SolarTerm::SUPPORTED_YEARS`] の範囲外の場合や、求めた日付が `year` 年に
+/// ならなかった場合は `None`。
+pub(crate) fn reach_longitude(
+    year: i32,
+    longitude: f64,
+    month: i64,
+    day: i64,
+) -> Option<(Date, JulianDateTime)> {
+    if !SolarTerm::SUPPORTED_YEARS.contains(&year) {
+        return None;
+    }
+    let jd = astronomy::universal_time(astronomy::find_solar_longitude_jd(
+        year as i64,
+        longitude,
+        month,
+        day,
+    ));
+    let time = astronomy::julian_day_to_jst(jd);
+    let date = Date::new(time.year, time.month, time.day).ok()?;
+    (date.year() == year).then_some((date, time))
+}
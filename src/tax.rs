@@ -0,0 +1,163 @@
+//! 国税の標準的な申告・納付期限。
+//!
+//! 所得税の確定申告（翌年 3 月 15 日）、源泉所得税の納付（翌月 10 日）、法人税の確定申告
+//! （事業年度終了の日の翌日から 2 か月以内）の期限を求めます。期限が土曜日・日曜日・祝日・
+//! 年末年始（12/29〜1/3）に当たる場合は、国税通則法第 10 条第 2 項に従い翌開庁日に延長します
+//! （[`crate::JPHoliday::government`] のカレンダーを用います）。
+//!
+//! 申告期限の延長の特例や納期の特例など、個別の事情による期限は扱いません。
+//!
+//! 期限は [`TaxDeadline`] の関連関数で求めます。
+
+use crate::date::Date;
+use crate::jpholiday::JPHoliday;
+use crate::period::{self, Period};
+use std::fmt;
+
+/// 期限の種類。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaxDeadlineKind {
+    /// 所得税の確定申告・納付（`tax_year` 年分）。
+    IncomeTaxReturn {
+        /// 対象の年分。
+        tax_year: i32,
+    },
+    /// 源泉所得税の納付（`year` 年 `month` 月に支払った給与等の分）。
+    WithholdingTax {
+        /// 給与等を支払った年。
+        year: i32,
+        /// 給与等を支払った月。
+        month: u32,
+    },
+    /// 法人税の確定申告・納付（`fiscal_year_end` に終了する事業年度の分）。
+    CorporateTaxReturn {
+        /// 事業年度終了の日。
+        fiscal_year_end: Date,
+    },
+}
+
+impl fmt::Display for TaxDeadlineKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaxDeadlineKind::IncomeTaxReturn { tax_year } => {
+                write!(f, "所得税の確定申告（{tax_year}年分）")
+            }
+            TaxDeadlineKind::WithholdingTax { year, month } => {
+                write!(f, "源泉所得税の納付（{year}年{month}月分）")
+            }
+            TaxDeadlineKind::CorporateTaxReturn { fiscal_year_end } => {
+                write!(f, "法人税の確定申告（{fiscal_year_end} 終了事業年度分）")
+            }
+        }
+    }
+}
+
+/// 申告・納付期限。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, TaxDeadline};
+///
+/// // 2025 年分の確定申告期限は 2026-03-15 (日) の翌日。
+/// let deadline = TaxDeadline::income_tax_return(2025).unwrap();
+/// assert_eq!(deadline.due_date, Date::new(2026, 3, 16).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaxDeadline {
+    /// 期限の種類。
+    pub kind: TaxDeadlineKind,
+    /// 法令上の期限日（休日による延長前）。
+    pub statutory_date: Date,
+    /// 実際の期限日（休日に当たる場合は翌開庁日）。
+    pub due_date: Date,
+}
+
+impl TaxDeadline {
+    /// 法令上の期限日から `due_date` を求めて期限を生成します。
+    fn from_statutory_date(kind: TaxDeadlineKind, statutory_date: Date) -> Self {
+        TaxDeadline {
+            kind,
+            statutory_date,
+            due_date: JPHoliday::government().deadline(statutory_date),
+        }
+    }
+
+    /// 休日のため期限が延長されたかどうかを返します。
+    pub fn is_extended(&self) -> bool {
+        self.due_date != self.statutory_date
+    }
+
+    /// `tax_year` 年分の所得税の確定申告期限（翌年 3 月 15 日）を返します。
+    ///
+    /// 期限日の年が `i32` の範囲を超える場合は `None`。
+    pub fn income_tax_return(tax_year: i32) -> Option<Self> {
+        let statutory_date = Date::new(tax_year.checked_add(1)?, 3, 15).ok()?;
+        Some(Self::from_statutory_date(
+            TaxDeadlineKind::IncomeTaxReturn { tax_year },
+            statutory_date,
+        ))
+    }
+
+    /// `year` 年 `month` 月に支払った給与等の源泉所得税の納付期限（翌月 10 日）を返します。
+    ///
+    /// `month` が 1〜12 でない場合や、期限日の年が `i32` の範囲を超える場合は `None`。
+    pub fn withholding_tax(year: i32, month: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        let (due_year, due_month) = if month == 12 {
+            (year.checked_add(1)?, 1)
+        } else {
+            (year, month + 1)
+        };
+        let statutory_date = Date::new(due_year, due_month, 10).ok()?;
+        Some(Self::from_statutory_date(
+            TaxDeadlineKind::WithholdingTax { year, month },
+            statutory_date,
+        ))
+    }
+
+    /// `fiscal_year_end` に終了する事業年度の法人税の確定申告期限を返します。
+    ///
+    /// 事業年度終了の日の翌日から 2 か月以内が期限で、民法第 143 条に従い 2 か月後の応当日の
+    /// 前日（応当日がなければその月の末日）が法令上の期限日です。期限日の年が `i32` の範囲を
+    /// 超える場合は `None`。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, TaxDeadline};
+    ///
+    /// // 2024-03-31 終了の事業年度は 2024-05-31 (金) が期限。
+    /// let deadline = TaxDeadline::corporate_tax_return(Date::new(2024, 3, 31).unwrap()).unwrap();
+    /// assert_eq!(deadline.due_date, Date::new(2024, 5, 31).unwrap());
+    /// // 2024-03-20 終了の事業年度は 2024-05-20 (月) が期限。
+    /// let deadline = TaxDeadline::corporate_tax_return(Date::new(2024, 3, 20).unwrap()).unwrap();
+    /// assert_eq!(deadline.due_date, Date::new(2024, 5, 20).unwrap());
+    /// ```
+    pub fn corporate_tax_return(fiscal_year_end: Date) -> Option<Self> {
+        let period =
+            period::period_deadline(fiscal_year_end, Period::Months(2), &JPHoliday::government())?;
+        Some(TaxDeadline {
+            kind: TaxDeadlineKind::CorporateTaxReturn { fiscal_year_end },
+            statutory_date: period.period_end,
+            due_date: period.deadline,
+        })
+    }
+
+    /// `year` 年中に法令上の期限日が来る所得税の確定申告（前年分）と源泉所得税の納付期限を、
+    /// 期限日順に返します。
+    ///
+    /// 法人税は事業年度によって異なるため含みません（[`TaxDeadline::corporate_tax_return`] を
+    /// 利用してください）。
+    pub fn deadlines_in(year: i32) -> Vec<Self> {
+        let previous = year.checked_sub(1);
+        let mut out: Vec<Self> = previous
+            .and_then(Self::income_tax_return)
+            .into_iter()
+            .chain(previous.and_then(|y| Self::withholding_tax(y, 12)))
+            .chain((1..=11).filter_map(|month| Self::withholding_tax(year, month)))
+            .collect();
+        out.sort_by_key(|d| d.statutory_date);
+        out
+    }
+}
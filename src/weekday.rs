@@ -0,0 +1,161 @@
+//! 曜日。
+
+use crate::date::Date;
+use std::fmt;
+
+/// 曜日（月曜始まり）。
+///
+/// # Examples
+/// ```
+/// use jpholiday::{Date, Weekday};
+///
+/// let date = Date::new(2024, 1, 8).unwrap();
+/// assert_eq!(date.day_of_week(), Weekday::Monday);
+/// assert_eq!(Weekday::Monday.japanese_name(), "月曜日");
+/// assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+/// assert_eq!(Weekday::from_iso(7), Some(Weekday::Sunday));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// 月曜日
+    Monday,
+    /// 火曜日
+    Tuesday,
+    /// 水曜日
+    Wednesday,
+    /// 木曜日
+    Thursday,
+    /// 金曜日
+    Friday,
+    /// 土曜日
+    Saturday,
+    /// 日曜日
+    Sunday,
+}
+
+impl Weekday {
+    /// 月曜日から日曜日までのすべての曜日。
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// 月曜日から日曜日までを順に返すイテレータを返します。
+    pub fn iter() -> impl DoubleEndedIterator<Item = Weekday> + ExactSizeIterator {
+        Weekday::ALL.into_iter()
+    }
+
+    /// ISO 曜日番号（月曜=1 〜 日曜=7）から曜日を返します。範囲外なら `None`。
+    pub fn from_iso(n: u32) -> Option<Weekday> {
+        n.checked_sub(1)
+            .and_then(|i| Weekday::ALL.get(i as usize))
+            .copied()
+    }
+
+    /// 月曜=0 〜 日曜=6 の番号から曜日を返します。範囲外なら `None`。
+    pub fn from_number_from_monday(n: u32) -> Option<Weekday> {
+        Weekday::ALL.get(n as usize).copied()
+    }
+
+    /// ISO 曜日番号（月曜=1 〜 日曜=7）を返します。
+    pub fn iso_number(self) -> u32 {
+        self.number_from_monday() + 1
+    }
+
+    /// 月曜=0 〜 日曜=6 の番号を返します。
+    pub fn number_from_monday(self) -> u32 {
+        self as u32
+    }
+
+    /// 翌日の曜日を返します（日曜日の次は月曜日）。
+    pub fn succ(self) -> Weekday {
+        Weekday::ALL[(self.number_from_monday() as usize + 1) % 7]
+    }
+
+    /// 前日の曜日を返します（月曜日の前は日曜日）。
+    pub fn pred(self) -> Weekday {
+        Weekday::ALL[(self.number_from_monday() as usize + 6) % 7]
+    }
+
+    /// 日本語の曜日名を返します（例: `"月曜日"`）。
+    pub fn japanese_name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "月曜日",
+            Weekday::Tuesday => "火曜日",
+            Weekday::Wednesday => "水曜日",
+            Weekday::Thursday => "木曜日",
+            Weekday::Friday => "金曜日",
+            Weekday::Saturday => "土曜日",
+            Weekday::Sunday => "日曜日",
+        }
+    }
+
+    /// 日本語の曜日の略記（漢字 1 文字）を返します（例: `"月"`）。
+    pub fn japanese_short_name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "月",
+            Weekday::Tuesday => "火",
+            Weekday::Wednesday => "水",
+            Weekday::Thursday => "木",
+            Weekday::Friday => "金",
+            Weekday::Saturday => "土",
+            Weekday::Sunday => "日",
+        }
+    }
+
+    /// 英語の曜日名を返します（例: `"Monday"`）。
+    pub fn english_name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.japanese_name())
+    }
+}
+
+impl Date {
+    /// 曜日を返します。
+    #[inline]
+    pub fn day_of_week(self) -> Weekday {
+        Weekday::ALL[self.weekday() as usize]
+    }
+
+    /// その月の第 `nth` 週の `weekday` に当たる日付を返します（例: 第 2 月曜日）。
+    ///
+    /// `nth` が 1〜5 の範囲外、`month` が不正、またはその月に該当する日がなければ `None`。
+    ///
+    /// # Examples
+    /// ```
+    /// use jpholiday::{Date, Weekday};
+    ///
+    /// // 2024 年 1 月の第 2 月曜日（成人の日）。
+    /// assert_eq!(
+    ///     Date::nth_weekday(2024, 1, 2, Weekday::Monday),
+    ///     Some(Date::new(2024, 1, 8).unwrap())
+    /// );
+    /// ```
+    pub fn nth_weekday(year: i32, month: u32, nth: u32, weekday: Weekday) -> Option<Date> {
+        if !(1..=5).contains(&nth) {
+            return None;
+        }
+        let first = Date::new(year, month, 1).ok()?;
+        let offset = (weekday.number_from_monday() + 7 - first.weekday()) % 7;
+        let date = first.add_days((offset + (nth - 1) * 7) as i64);
+        (date.month() == month).then_some(date)
+    }
+}
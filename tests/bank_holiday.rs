@@ -0,0 +1,67 @@
+//! 銀行休業日のプリセットを検証する。
+
+use jpholiday::{BankHoliday, Date, HolidayKind, JPHoliday, Locale};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn year_end_closures_with_names() {
+    let bank = JPHoliday::bank();
+    assert_eq!(
+        bank.is_holiday_name(d(2023, 12, 31)).as_deref(),
+        Some("年末休業日")
+    );
+    assert_eq!(bank.is_holiday_name(d(2024, 1, 1)).as_deref(), Some("元日"));
+    assert_eq!(
+        bank.is_holiday_name(d(2024, 1, 3)).as_deref(),
+        Some("年始休業日")
+    );
+    assert!(!bank.is_holiday(d(2024, 1, 4)));
+    assert!(!bank.is_holiday(d(2023, 12, 30)));
+    assert_eq!(bank.holidays(d(2024, 1, 2))[0].kind, HolidayKind::Original);
+
+    // 通常の JPHoliday には含まれない。
+    assert!(!JPHoliday::new().is_holiday(d(2023, 12, 31)));
+}
+
+#[test]
+fn substitute_holiday_and_closure_on_same_day() {
+    // 2017-01-02 は元日の振替休日でもある。
+    let names: Vec<String> = JPHoliday::bank()
+        .holidays(d(2017, 1, 2))
+        .into_iter()
+        .map(|h| h.name)
+        .collect();
+    assert_eq!(names, ["元日 振替休日", "年始休業日"]);
+}
+
+#[test]
+fn bank_business_days() {
+    let bank = JPHoliday::bank();
+    // 2024-12-30 (月) の翌営業日は 2025-01-06 (月)。
+    assert_eq!(bank.next_business_day(d(2024, 12, 30)), d(2025, 1, 6));
+    assert_eq!(bank.previous_business_day(d(2025, 1, 6)), d(2024, 12, 30));
+    assert_eq!(
+        bank.business_days_between(d(2024, 12, 27), d(2025, 1, 7)),
+        3
+    );
+    assert!(JPHoliday::new().is_business_day(d(2024, 12, 31)));
+}
+
+#[test]
+fn localized_names() {
+    let mut bank = JPHoliday::with_locale(Locale::English);
+    bank.register(BankHoliday);
+    assert_eq!(
+        bank.is_holiday_name(d(2023, 12, 31)).as_deref(),
+        Some("Year-End Bank Holiday")
+    );
+    bank.set_locale(Locale::Hiragana);
+    assert_eq!(
+        bank.is_holiday_name(d(2024, 1, 2)).as_deref(),
+        Some("ねんしきゅうぎょうび")
+    );
+}
@@ -0,0 +1,94 @@
+//! 飛び石連休の休暇取得案を検証する。
+
+use jpholiday::{Date, JPHoliday, OriginalHolidayChecker};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn golden_week_2025() {
+    let jp = JPHoliday::new();
+    // 5/3(土)〜5/6(火) の後の 5/7〜5/9 を休むと翌週末までの 9 連休。
+    let plans = jp.bridge_suggestions(d(2025, 4, 1), d(2025, 5, 31), 3);
+    let best = &plans[0];
+    assert_eq!(
+        best.leave_days,
+        [d(2025, 5, 7), d(2025, 5, 8), d(2025, 5, 9)]
+    );
+    assert_eq!((best.start, best.end), (d(2025, 5, 3), d(2025, 5, 11)));
+    assert_eq!(best.days(), 9);
+    assert_eq!(best.days_off_gained(), 6);
+    // 4/29(火) 昭和の日との間の 4/30〜5/2 を休むと 8 連休。
+    let second = &plans[1];
+    assert_eq!(second.leave_days[0], d(2025, 4, 30));
+    assert_eq!((second.start, second.end), (d(2025, 4, 29), d(2025, 5, 6)));
+    assert!(plans.windows(2).all(|w| w[0].days() >= w[1].days()));
+
+    // 4/28 も休めば 4/26(土) からの 11 連休。
+    let plans = jp.bridge_suggestions(d(2025, 4, 1), d(2025, 5, 31), 4);
+    assert_eq!(
+        (plans[0].start, plans[0].end),
+        (d(2025, 4, 26), d(2025, 5, 6))
+    );
+}
+
+#[test]
+fn ties_are_ordered_by_date_and_edges_extend() {
+    // 2024-01-05(金) を休むと 1/6〜1/8 の 3 連休とつながり、範囲外の休日も数える。
+    let plans = jpholiday::bridge_suggestions(d(2024, 1, 5), d(2024, 1, 5), 1);
+    assert_eq!(plans.len(), 1);
+    assert_eq!(
+        (plans[0].start, plans[0].end),
+        (d(2024, 1, 5), d(2024, 1, 8))
+    );
+
+    let plans = jpholiday::bridge_suggestions(d(2024, 1, 15), d(2024, 1, 26), 1);
+    let firsts: Vec<(Date, i64)> = plans
+        .iter()
+        .take(3)
+        .map(|p| (p.leave_days[0], p.days()))
+        .collect();
+    assert_eq!(
+        firsts,
+        [
+            (d(2024, 1, 15), 3),
+            (d(2024, 1, 19), 3),
+            (d(2024, 1, 22), 3)
+        ]
+    );
+}
+
+#[test]
+fn empty_budget_or_short_range() {
+    assert!(jpholiday::bridge_suggestions(d(2024, 1, 1), d(2024, 12, 31), 0).is_empty());
+    // 範囲内の営業日は 1/9〜1/10 の 2 日のみ。
+    assert!(jpholiday::bridge_suggestions(d(2024, 1, 6), d(2024, 1, 10), 3).is_empty());
+}
+
+#[test]
+fn company_holidays_are_honored() {
+    struct Foundation;
+    impl OriginalHolidayChecker for Foundation {
+        fn is_holiday(&self, date: Date) -> bool {
+            date == Date::new(2025, 5, 2).unwrap()
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "創立記念日".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    let plans = jp.bridge_suggestions(d(2025, 4, 1), d(2025, 5, 31), 1);
+    assert_eq!(plans[0].leave_days, [d(2025, 5, 2)]);
+    assert_eq!(plans[0].days(), 5);
+
+    jp.register(Foundation);
+    let plans = jp.bridge_suggestions(d(2025, 4, 1), d(2025, 5, 31), 1);
+    assert_eq!(plans[0].leave_days, [d(2025, 5, 1)]);
+    assert_eq!(
+        (plans[0].start, plans[0].end),
+        (d(2025, 5, 1), d(2025, 5, 6))
+    );
+}
@@ -0,0 +1,151 @@
+//! `JPHoliday` の営業日計算を検証する。
+
+use jpholiday::{Date, JPHoliday, OriginalHolidayChecker, Period, RollConvention, Weekend};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn business_day_respects_weekend_and_holidays() {
+    let jp = JPHoliday::new();
+    assert!(jp.is_business_day(d(2024, 1, 4))); // 木曜日
+    assert!(!jp.is_business_day(d(2024, 1, 6))); // 土曜日
+    assert!(!jp.is_business_day(d(2024, 1, 7))); // 日曜日
+    assert!(!jp.is_business_day(d(2024, 1, 8))); // 成人の日
+}
+
+#[test]
+fn next_and_previous_business_day() {
+    let jp = JPHoliday::new();
+    // 2024-01-05 (金) の翌営業日は 3 連休明けの 1/9 (火)。
+    assert_eq!(jp.next_business_day(d(2024, 1, 5)), d(2024, 1, 9));
+    assert_eq!(jp.previous_business_day(d(2024, 1, 9)), d(2024, 1, 5));
+    // 2019 年のゴールデンウィーク 10 連休。
+    assert_eq!(jp.next_business_day(d(2019, 4, 26)), d(2019, 5, 7));
+}
+
+#[test]
+fn add_business_days_both_directions() {
+    let jp = JPHoliday::new();
+    assert_eq!(jp.add_business_days(d(2024, 1, 5), 0), d(2024, 1, 5));
+    assert_eq!(jp.add_business_days(d(2024, 1, 5), 1), d(2024, 1, 9));
+    assert_eq!(jp.add_business_days(d(2024, 1, 5), 5), d(2024, 1, 15));
+    assert_eq!(jp.add_business_days(d(2024, 1, 15), -5), d(2024, 1, 5));
+    // 休日を起点にしても、起点を数えずに進む。
+    assert_eq!(jp.add_business_days(d(2024, 1, 6), 1), d(2024, 1, 9));
+}
+
+#[test]
+fn business_days_between_counts_half_open_range() {
+    let jp = JPHoliday::new();
+    assert_eq!(jp.business_days_between(d(2024, 1, 5), d(2024, 1, 15)), 5);
+    assert_eq!(jp.business_days_between(d(2024, 1, 15), d(2024, 1, 5)), -5);
+    assert_eq!(jp.business_days_between(d(2024, 1, 5), d(2024, 1, 5)), 0);
+    // 2024 年 1 月の営業日数（12/31 の翌日から 1/31 まで）。
+    assert_eq!(
+        jp.business_days_between(d(2023, 12, 31), d(2024, 1, 31)),
+        21
+    );
+
+    let start = d(2024, 4, 1);
+    let end = d(2024, 6, 28);
+    let n = jp.business_days_between(start, end);
+    assert_eq!(jp.add_business_days(start, n), end);
+}
+
+#[test]
+fn configurable_weekend() {
+    let mut jp = JPHoliday::new();
+    assert_eq!(jp.weekend(), Weekend::SATURDAY_SUNDAY);
+
+    jp.set_weekend(Weekend::SUNDAY);
+    assert!(jp.is_business_day(d(2024, 1, 6))); // 土曜日も営業
+    assert!(!jp.is_business_day(d(2024, 1, 7)));
+    assert_eq!(jp.next_business_day(d(2024, 1, 5)), d(2024, 1, 6));
+
+    let fri_sat = Weekend::from_iso_weekdays(&[5, 6]).unwrap();
+    jp.set_weekend(fri_sat);
+    assert!(!jp.is_business_day(d(2024, 1, 5)));
+    assert!(jp.is_business_day(d(2024, 1, 7)));
+
+    assert_eq!(Weekend::from_iso_weekdays(&[0]), None);
+    assert_eq!(Weekend::from_iso_weekdays(&[1, 2, 3, 4, 5, 6, 7]), None);
+    assert!(!Weekend::NONE.contains(d(2024, 1, 7)));
+}
+
+#[test]
+fn registered_checkers_are_days_off() {
+    struct FoundingDay;
+    impl OriginalHolidayChecker for FoundingDay {
+        fn is_holiday(&self, date: Date) -> bool {
+            date == d(2024, 1, 9)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "創立記念日".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(FoundingDay);
+    assert!(!jp.is_business_day(d(2024, 1, 9)));
+    assert_eq!(jp.next_business_day(d(2024, 1, 5)), d(2024, 1, 10));
+    assert_eq!(jp.business_days_between(d(2024, 1, 5), d(2024, 1, 12)), 3);
+}
+
+#[test]
+fn search_gives_up_when_every_day_is_closed() {
+    struct AlwaysClosed;
+    impl OriginalHolidayChecker for AlwaysClosed {
+        fn is_holiday(&self, _date: Date) -> bool {
+            true
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "休業".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(AlwaysClosed);
+    let date = d(2024, 1, 1);
+    assert_eq!(jp.checked_next_business_day(date), None);
+    assert_eq!(jp.checked_previous_business_day(date), None);
+    assert_eq!(jp.checked_add_business_days(date, 1), None);
+    assert_eq!(jp.checked_add_business_days(date, 0), Some(date));
+    assert_eq!(
+        jp.checked_adjust(date, RollConvention::ModifiedFollowing),
+        None
+    );
+    assert_eq!(jp.checked_deadline(date), None);
+    assert_eq!(jp.period_deadline(date, Period::Days(1)), None);
+
+    // 営業日が 1 日だけの暦では、休暇の取得案の連休を前後それぞれ 3660 日で打ち切る。
+    struct OpenOnlyOnce;
+    impl OriginalHolidayChecker for OpenOnlyOnce {
+        fn is_holiday(&self, date: Date) -> bool {
+            date != d(2024, 1, 10)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "休業".to_string()
+        }
+    }
+    let mut jp = JPHoliday::new();
+    jp.register(OpenOnlyOnce);
+    let plans = jp.bridge_suggestions(d(2024, 1, 1), d(2024, 1, 31), 1);
+    assert_eq!(plans.len(), 1);
+    assert_eq!(plans[0].start, d(2024, 1, 10).add_days(-3660));
+    assert_eq!(plans[0].end, d(2024, 1, 10).add_days(3660));
+
+    // 営業日があれば `Date` を返す版と同じ結果になる。
+    let jp = JPHoliday::new();
+    assert_eq!(
+        jp.checked_next_business_day(date),
+        Some(jp.next_business_day(date))
+    );
+    assert_eq!(jp.checked_deadline(date), Some(d(2024, 1, 2)));
+    assert_eq!(
+        jpholiday::checked_adjust(date, RollConvention::Preceding),
+        Some(d(2023, 12, 29))
+    );
+}
@@ -0,0 +1,143 @@
+//! 連休の検出を検証する。
+
+use jpholiday::{Date, JPHoliday, OriginalHolidayChecker, Weekend};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn golden_week_and_silver_week() {
+    let blocks = jpholiday::consecutive_holidays(d(2015, 1, 1), d(2015, 12, 31), 5);
+    let spans: Vec<(Date, Date)> = blocks.iter().map(|b| (b.start, b.end)).collect();
+    // 2015 年: ゴールデンウィーク（5/2〜5/6）とシルバーウィーク（9/19〜9/23）。
+    assert_eq!(
+        spans,
+        vec![
+            (d(2015, 5, 2), d(2015, 5, 6)),
+            (d(2015, 9, 19), d(2015, 9, 23))
+        ]
+    );
+    let silver = &blocks[1];
+    assert_eq!(silver.days(), 5);
+    let names: Vec<&str> = silver.holidays.iter().map(|h| h.name.as_str()).collect();
+    assert_eq!(names, ["敬老の日", "国民の休日", "秋分の日"]);
+}
+
+#[test]
+fn min_days_filter_and_weekends() {
+    // 2024 年 1 月: 年末の週末から続く元日、成人の日の 3 連休、通常の週末。
+    let all = jpholiday::consecutive_holidays(d(2024, 1, 1), d(2024, 1, 14), 1);
+    let spans: Vec<(Date, i64)> = all.iter().map(|b| (b.start, b.days())).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (d(2023, 12, 30), 3),
+            (d(2024, 1, 6), 3),
+            (d(2024, 1, 13), 2)
+        ]
+    );
+    assert!(all[1].contains(d(2024, 1, 8)));
+    assert!(all[2].holidays.is_empty());
+
+    let three = jpholiday::consecutive_holidays(d(2024, 1, 1), d(2024, 1, 14), 3);
+    assert_eq!(three.len(), 2);
+    let none = jpholiday::consecutive_holidays(d(2024, 1, 10), d(2024, 1, 12), 1);
+    assert!(none.is_empty());
+}
+
+#[test]
+fn blocks_at_range_edges_are_not_clipped() {
+    // 範囲の開始日が連休の途中でも、連休全体を返す。
+    let blocks = jpholiday::consecutive_holidays(d(2019, 5, 3), d(2019, 5, 3), 1);
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(
+        (blocks[0].start, blocks[0].end),
+        (d(2019, 4, 27), d(2019, 5, 6))
+    );
+}
+
+#[test]
+fn instance_weekend_and_company_holidays() {
+    struct YearEnd;
+    impl OriginalHolidayChecker for YearEnd {
+        fn is_holiday(&self, date: Date) -> bool {
+            (date.month() == 12 && date.day() >= 29) || (date.month() == 1 && date.day() <= 3)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "年末年始休暇".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(YearEnd);
+    let blocks = jp.consecutive_holidays(d(2023, 12, 1), d(2024, 1, 31), 5);
+    assert_eq!(blocks.len(), 1);
+    // 12/29(金)〜1/3(水) の 6 日間（12/30, 12/31 は週末、1/1 は元日）。
+    assert_eq!(
+        (blocks[0].start, blocks[0].end),
+        (d(2023, 12, 29), d(2024, 1, 3))
+    );
+
+    jp.set_weekend(Weekend::SUNDAY);
+    let blocks = jp.consecutive_holidays(d(2024, 1, 6), d(2024, 1, 8), 2);
+    assert_eq!(
+        (blocks[0].start, blocks[0].end),
+        (d(2024, 1, 7), d(2024, 1, 8))
+    );
+}
+
+#[test]
+fn endless_holidays_are_cut_off() {
+    struct EveryDay;
+    impl OriginalHolidayChecker for EveryDay {
+        fn is_holiday(&self, _date: Date) -> bool {
+            true
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "休業日".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(EveryDay);
+    let blocks = jp.consecutive_holidays(d(2024, 1, 1), d(2024, 1, 31), 1);
+    assert_eq!(blocks.len(), 1);
+    // 範囲の前後それぞれ 3660 日で打ち切る。
+    assert_eq!(blocks[0].start, d(2024, 1, 1).add_days(-3660));
+    assert_eq!(blocks[0].end, d(2024, 1, 31).add_days(3660));
+}
+
+#[test]
+fn runs_at_the_ends_of_the_date_range() {
+    struct EveryDay;
+    impl OriginalHolidayChecker for EveryDay {
+        fn is_holiday(&self, _date: Date) -> bool {
+            true
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "休業日".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(EveryDay);
+    // 延ばす範囲は `Date` が表現できる日付までに丸める。
+    let blocks = jp.consecutive_holidays(d(i32::MAX, 12, 1), d(i32::MAX, 12, 31), 1);
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].start, d(i32::MAX, 12, 1).add_days(-3660));
+    assert_eq!(blocks[0].end, d(i32::MAX, 12, 31));
+
+    let blocks = jp.consecutive_holidays(d(i32::MIN, 1, 1), d(i32::MIN, 1, 31), 1);
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].start, d(i32::MIN, 1, 1));
+    assert_eq!(blocks[0].end, d(i32::MIN, 1, 31).add_days(3660));
+
+    // 週末だけの既定の暦でも、最後の日を越えて数えない。
+    let blocks = jpholiday::consecutive_holidays(d(i32::MAX, 12, 1), d(i32::MAX, 12, 31), 1);
+    assert!(!blocks.is_empty());
+    assert!(blocks.iter().all(|b| b.end <= d(i32::MAX, 12, 31)));
+    let blocks = jpholiday::consecutive_holidays(d(i32::MIN, 1, 1), d(i32::MIN, 1, 31), 1);
+    assert!(blocks.iter().all(|b| b.start >= d(i32::MIN, 1, 1)));
+}
@@ -233,3 +233,19 @@ fn solar_ecliptic_longitude_approximate_values() {
         assert!(diff < 5.0, "longitude {lon} not within 5° of {expected}");
     }
 }
+
+#[test]
+fn equinox_holidays_use_dynamical_time() {
+    // 祝日の春分日・秋分日は ΔT を差し引かずに判定する（二十四節気の瞬刻とは扱いが異なる）。
+    // 差し引くと日付が変わる年でも、従来どおりの日付になる。
+    assert_eq!(astronomy::calculate_autumn_equinox(2198), 23);
+    assert_eq!(astronomy::calculate_vernal_equinox(2253), 21);
+    assert_eq!(astronomy::calculate_vernal_equinox(2286), 21);
+    assert_eq!(astronomy::calculate_vernal_equinox(2352), 21);
+    assert_eq!(astronomy::calculate_autumn_equinox(2996), 22);
+    let date = |y, m, d| jpholiday::Date::new(y, m, d).unwrap();
+    assert!(jpholiday::is_holiday(date(2198, 9, 23)));
+    assert!(!jpholiday::is_holiday(date(2198, 9, 22)));
+    assert!(jpholiday::is_holiday(date(2253, 3, 21)));
+    assert!(jpholiday::is_holiday(date(2996, 9, 22)));
+}
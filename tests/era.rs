@@ -0,0 +1,99 @@
+//! 和暦（元号）との相互変換を検証する。
+
+use jpholiday::{Date, DateError, Era, JapaneseDate, era_year_label};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[inline]
+fn jd(era: Era, year: u32, month: u32, day: u32) -> JapaneseDate {
+    JapaneseDate {
+        era,
+        year,
+        month,
+        day,
+    }
+}
+
+#[test]
+fn transition_days() {
+    let cases = [
+        (d(1868, 1, 25), jd(Era::Meiji, 1, 1, 25)),
+        (d(1912, 7, 29), jd(Era::Meiji, 45, 7, 29)),
+        (d(1912, 7, 30), jd(Era::Taisho, 1, 7, 30)),
+        (d(1926, 12, 24), jd(Era::Taisho, 15, 12, 24)),
+        (d(1926, 12, 25), jd(Era::Showa, 1, 12, 25)),
+        (d(1989, 1, 7), jd(Era::Showa, 64, 1, 7)),
+        (d(1989, 1, 8), jd(Era::Heisei, 1, 1, 8)),
+        (d(2019, 4, 30), jd(Era::Heisei, 31, 4, 30)),
+        (d(2019, 5, 1), jd(Era::Reiwa, 1, 5, 1)),
+        (d(2024, 1, 1), jd(Era::Reiwa, 6, 1, 1)),
+    ];
+    for (date, japanese) in cases {
+        assert_eq!(date.to_japanese(), Some(japanese), "{date}");
+        assert_eq!(japanese.to_date(), Ok(date), "{japanese}");
+    }
+    assert_eq!(d(1868, 1, 24).to_japanese(), None);
+}
+
+#[test]
+fn gannen_convention() {
+    assert_eq!(era_year_label(1), "元年");
+    assert_eq!(era_year_label(31), "31年");
+    assert_eq!(
+        d(2019, 5, 1).to_japanese().unwrap().to_string(),
+        "令和元年5月1日"
+    );
+    assert_eq!(
+        d(2024, 2, 23).to_japanese().unwrap().to_string(),
+        "令和6年2月23日"
+    );
+}
+
+#[test]
+fn out_of_era_is_rejected() {
+    assert_eq!(
+        Date::from_japanese(Era::Heisei, 31, 5, 1),
+        Err(DateError::OutOfEra {
+            era: Era::Heisei,
+            year: 31,
+            month: 5,
+            day: 1
+        })
+    );
+    assert!(matches!(
+        Date::from_japanese(Era::Showa, 64, 1, 8),
+        Err(DateError::OutOfEra { .. })
+    ));
+    assert!(matches!(
+        Date::from_japanese(Era::Reiwa, 1, 4, 30),
+        Err(DateError::OutOfEra { .. })
+    ));
+    assert!(matches!(
+        Date::from_japanese(Era::Reiwa, 0, 5, 1),
+        Err(DateError::OutOfEra { .. })
+    ));
+    // 元号内でも実在しない日付は InvalidDate。
+    assert!(matches!(
+        Date::from_japanese(Era::Reiwa, 5, 2, 29),
+        Err(DateError::InvalidDate { .. })
+    ));
+    assert_eq!(
+        Date::from_japanese(Era::Heisei, 31, 5, 1)
+            .unwrap_err()
+            .to_string(),
+        "date outside of era: Heisei 31-05-01"
+    );
+}
+
+#[test]
+fn era_metadata() {
+    assert_eq!(Era::of(d(2019, 5, 1)), Some(Era::Reiwa));
+    assert_eq!(Era::Heisei.end(), Some(d(2019, 4, 30)));
+    assert_eq!(Era::Reiwa.end(), None);
+    assert_eq!(Era::from_name("昭和"), Some(Era::Showa));
+    assert_eq!(Era::from_name("慶応"), None);
+    assert_eq!(Era::Reiwa.initial(), 'R');
+}
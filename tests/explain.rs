@@ -0,0 +1,107 @@
+//! 祝日判定の根拠の説明を検証する。
+
+use jpholiday::{Date, HolidayReason, JPHoliday, Locale, OriginalHolidayChecker};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn builtin_rule_with_legal_basis() {
+    let explanation = jpholiday::explain(d(2024, 1, 8));
+    assert!(explanation.is_holiday());
+    let HolidayReason::Builtin { holiday, rule } = &explanation.reasons[0] else {
+        panic!("expected a builtin holiday: {explanation:?}");
+    };
+    assert_eq!(holiday.name, "成人の日");
+    assert_eq!(rule.date_rule, "1月の第2月曜日");
+    assert_eq!((rule.since, rule.until), (2000, None));
+    assert!(rule.legal_basis.contains("平成10年法律第141号"));
+
+    // 1999 年以前は 1 月 15 日。
+    let HolidayReason::Builtin { rule, .. } = &jpholiday::explain(d(1999, 1, 15)).reasons[0] else {
+        panic!("expected a builtin holiday");
+    };
+    assert_eq!((rule.date_rule, rule.until), ("1月15日", Some(1999)));
+
+    // 東京オリンピックによる特例。
+    let HolidayReason::Builtin { rule, .. } = &jpholiday::explain(d(2021, 7, 22)).reasons[0] else {
+        panic!("expected a builtin holiday");
+    };
+    assert_eq!((rule.since, rule.until), (2021, Some(2021)));
+    assert!(rule.legal_basis.contains("令和2年法律第68号"));
+}
+
+#[test]
+fn substitute_chain_walks_back_to_sunday() {
+    // 2008-05-04 (日・みどりの日)、5/5 (月・こどもの日) の後の 5/6 (火) が振替休日。
+    let explanation = jpholiday::explain(d(2008, 5, 6));
+    let HolidayReason::Substitute {
+        holiday,
+        chain,
+        legal_basis,
+    } = &explanation.reasons[0]
+    else {
+        panic!("expected a substitute holiday: {explanation:?}");
+    };
+    assert_eq!(holiday.name, "みどりの日 振替休日");
+    let walked: Vec<(Date, &str)> = chain.iter().map(|h| (h.date, h.name.as_str())).collect();
+    assert_eq!(
+        walked,
+        [(d(2008, 5, 5), "こどもの日"), (d(2008, 5, 4), "みどりの日")]
+    );
+    assert!(legal_basis.contains("平成17年法律第43号"));
+
+    // 2007 年より前の規定。
+    let HolidayReason::Substitute { legal_basis, .. } =
+        &jpholiday::explain(d(2006, 1, 2)).reasons[0]
+    else {
+        panic!("expected a substitute holiday");
+    };
+    assert!(legal_basis.contains("昭和48年法律第10号"));
+}
+
+#[test]
+fn citizens_holiday_is_sandwiched() {
+    let explanation = jpholiday::explain(d(2015, 9, 22));
+    let HolidayReason::Citizens { before, after, .. } = &explanation.reasons[0] else {
+        panic!("expected a citizens' holiday: {explanation:?}");
+    };
+    assert_eq!(
+        (before.date, before.name.as_str()),
+        (d(2015, 9, 21), "敬老の日")
+    );
+    assert_eq!(
+        (after.date, after.name.as_str()),
+        (d(2015, 9, 23), "秋分の日")
+    );
+    assert!(explanation.to_string().contains("前日 2015-09-21 敬老の日"));
+}
+
+#[test]
+fn non_holiday_and_original() {
+    let explanation = jpholiday::explain(d(2024, 1, 9));
+    assert!(!explanation.is_holiday());
+    assert_eq!(explanation.to_string(), "2024-01-09: 祝日ではありません");
+
+    struct Founding;
+    impl OriginalHolidayChecker for Founding {
+        fn is_holiday(&self, date: Date) -> bool {
+            date == Date::new(2024, 1, 9).unwrap()
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "創立記念日".to_string()
+        }
+    }
+    let mut jp = JPHoliday::with_locale(Locale::English);
+    jp.register(Founding);
+    let explanation = jp.explain(d(2024, 1, 9));
+    assert!(
+        matches!(&explanation.reasons[0], HolidayReason::Original { holiday } if holiday.name == "創立記念日")
+    );
+    assert_eq!(
+        jp.explain(d(2024, 1, 8)).reasons[0].holiday().name,
+        "Coming of Age Day"
+    );
+}
@@ -0,0 +1,83 @@
+//! 会計年度と四半期の計算を検証する。
+
+use jpholiday::{Date, FiscalYear, JPHoliday, OriginalHolidayChecker};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn fiscal_year_of_date() {
+    assert_eq!(
+        FiscalYear::of(d(2025, 4, 1), 4),
+        Some(FiscalYear::april(2025))
+    );
+    assert_eq!(
+        FiscalYear::of(d(2025, 3, 31), 4),
+        Some(FiscalYear::april(2024))
+    );
+    // 1 月始まりは暦年と一致する。
+    let fy = FiscalYear::of(d(2025, 12, 31), 1).unwrap();
+    assert_eq!((fy.start(), fy.end()), (d(2025, 1, 1), d(2025, 12, 31)));
+    // 10 月始まり。
+    let fy = FiscalYear::of(d(2025, 9, 30), 10).unwrap();
+    assert_eq!(fy.year(), 2024);
+    assert_eq!((fy.start(), fy.end()), (d(2024, 10, 1), d(2025, 9, 30)));
+    assert!(FiscalYear::of(d(2025, 1, 1), 13).is_none());
+    assert!(FiscalYear::new(2025, 0).is_none());
+    assert_eq!(FiscalYear::april(2025).succ(), FiscalYear::april(2026));
+    assert_eq!(FiscalYear::april(2025).pred(), FiscalYear::april(2024));
+}
+
+#[test]
+fn quarters() {
+    let fy = FiscalYear::april(2025);
+    assert_eq!(fy.quarter(1), Some((d(2025, 4, 1), d(2025, 6, 30))));
+    assert_eq!(fy.quarter(4), Some((d(2026, 1, 1), d(2026, 3, 31))));
+    assert_eq!(fy.quarter(5), None);
+    assert_eq!(fy.quarter_of(d(2025, 12, 31)), Some(3));
+    assert_eq!(fy.quarter_of(d(2026, 4, 1)), None);
+
+    // 2 月始まりでは第 4 四半期が翌年 1 月に終わる。
+    let fy = FiscalYear::new(2024, 2).unwrap();
+    assert_eq!(fy.quarter(4), Some((d(2024, 11, 1), d(2025, 1, 31))));
+    assert_eq!(fy.quarter_of(d(2025, 1, 15)), Some(4));
+}
+
+#[test]
+fn fiscal_year_holidays() {
+    let fy = FiscalYear::april(2024);
+    let global = jpholiday::fiscal_year_holidays(fy);
+    assert_eq!(global.len(), 21);
+    assert_eq!(global[0], (d(2024, 4, 29), "昭和の日".to_string()));
+    assert_eq!(global[20], (d(2025, 3, 20), "春分の日".to_string()));
+    assert!(global.iter().all(|(date, _)| fy.contains(*date)));
+
+    let jp = JPHoliday::new();
+    let instance: Vec<(Date, String)> = jp
+        .fiscal_year_holidays(fy)
+        .into_iter()
+        .map(|h| h.into_tuple())
+        .collect();
+    assert_eq!(instance, global);
+}
+
+#[test]
+fn quarterly_business_days_with_company_holidays() {
+    struct SummerBreak;
+    impl OriginalHolidayChecker for SummerBreak {
+        fn is_holiday(&self, date: Date) -> bool {
+            date.month() == 8 && (13..=16).contains(&date.day())
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "夏季休暇".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    let fy = FiscalYear::april(2024);
+    assert_eq!(jp.quarterly_business_days(fy), [62, 62, 64, 59]);
+    jp.register(SummerBreak);
+    assert_eq!(jp.quarterly_business_days(fy), [62, 58, 64, 59]);
+}
@@ -0,0 +1,50 @@
+//! 行政機関の休日と提出期限の計算を検証する。
+
+use jpholiday::{Date, GovernmentHoliday, JPHoliday};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn year_end_closures() {
+    let gov = JPHoliday::government();
+    for day in [
+        d(2024, 12, 29),
+        d(2024, 12, 30),
+        d(2024, 12, 31),
+        d(2025, 1, 2),
+        d(2025, 1, 3),
+    ] {
+        assert_eq!(
+            gov.is_holiday_name(day).as_deref(),
+            Some("年末年始の休日"),
+            "{day}"
+        );
+    }
+    assert_eq!(gov.is_holiday_name(d(2025, 1, 1)).as_deref(), Some("元日"));
+    assert!(!gov.is_holiday(d(2024, 12, 28)));
+    assert!(!gov.is_holiday(d(2025, 1, 4)));
+    assert!(!JPHoliday::new().is_holiday(d(2024, 12, 30)));
+}
+
+#[test]
+fn deadlines_move_to_next_open_day() {
+    let gov = JPHoliday::government();
+    // 2025-01-04 (土)・1/5 (日) を経て 1/6 (月) に満了する。
+    assert_eq!(gov.deadline(d(2024, 12, 30)), d(2025, 1, 6));
+    // 2024-01-08 は成人の日。
+    assert_eq!(gov.deadline(d(2024, 1, 6)), d(2024, 1, 9));
+    assert_eq!(gov.deadline(d(2024, 1, 9)), d(2024, 1, 9));
+
+    // 年末年始の休日を登録しない場合は 12/30 (月) のまま。
+    assert_eq!(JPHoliday::new().deadline(d(2024, 12, 30)), d(2024, 12, 30));
+}
+
+#[test]
+fn registered_checker_matches_preset() {
+    let mut jp = JPHoliday::new();
+    jp.register(GovernmentHoliday);
+    assert_eq!(jp.next_business_day(d(2024, 12, 27)), d(2025, 1, 6));
+}
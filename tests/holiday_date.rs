@@ -0,0 +1,85 @@
+//! 祝日の識別子・名称と年から日付を求める逆引きを検証する。
+
+use jpholiday::{BuiltinHoliday, Date, HolidayId, HolidayKind};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn matches_year_scan() {
+    for year in [
+        1949, 1966, 1989, 1999, 2000, 2007, 2016, 2019, 2020, 2021, 2022, 2031, 2050,
+    ] {
+        for b in BuiltinHoliday::ALL {
+            let scanned = jpholiday::year_holidays(year)
+                .into_iter()
+                .find_map(|(date, _)| {
+                    jpholiday::holidays(date)
+                        .iter()
+                        .any(|h| h.kind == HolidayKind::Builtin(b))
+                        .then_some(date)
+                });
+            assert_eq!(b.date_in(year), scanned, "{b:?} in {year}");
+        }
+    }
+}
+
+#[test]
+fn olympic_special_dates() {
+    assert_eq!(BuiltinHoliday::SeaDay.date_in(2020), Some(d(2020, 7, 23)));
+    assert_eq!(
+        BuiltinHoliday::SportsDay.date_in(2020),
+        Some(d(2020, 7, 24))
+    );
+    assert_eq!(
+        BuiltinHoliday::MountainDay.date_in(2020),
+        Some(d(2020, 8, 10))
+    );
+    assert_eq!(BuiltinHoliday::SeaDay.date_in(2021), Some(d(2021, 7, 22)));
+    assert_eq!(
+        BuiltinHoliday::SportsDay.date_in(2021),
+        Some(d(2021, 7, 23))
+    );
+    assert_eq!(
+        BuiltinHoliday::MountainDay.date_in(2021),
+        Some(d(2021, 8, 8))
+    );
+}
+
+#[test]
+fn holidays_that_did_not_exist() {
+    assert_eq!(BuiltinHoliday::EmperorsBirthday.date_in(2019), None);
+    assert_eq!(BuiltinHoliday::HealthAndSportsDay.date_in(2020), None);
+    assert_eq!(BuiltinHoliday::ShowaDay.date_in(2006), None);
+    assert_eq!(BuiltinHoliday::NewYear.date_in(1948), None); // 祝日法の施行前
+    assert_eq!(BuiltinHoliday::ExtraHoliday1959.date_in(1960), None);
+}
+
+#[test]
+fn lookup_by_name_and_id() {
+    assert_eq!(
+        jpholiday::holiday_date("海の日", 2031),
+        Some(d(2031, 7, 21))
+    );
+    assert_eq!(
+        jpholiday::holiday_date("天皇誕生日", 1988),
+        Some(d(1988, 4, 29))
+    );
+    assert_eq!(
+        jpholiday::holiday_date("天皇誕生日", 2024),
+        Some(d(2024, 2, 23))
+    );
+    assert_eq!(jpholiday::holiday_date("元日 振替休日", 2017), None);
+    assert_eq!(jpholiday::holiday_date("存在しない日", 2024), None);
+    assert_eq!(
+        BuiltinHoliday::from_name("山の日"),
+        Some(BuiltinHoliday::MountainDay)
+    );
+
+    assert_eq!(HolidayId::ShowaDay.date_in(2024), Some(d(2024, 4, 29)));
+    assert_eq!(HolidayId::GreeneryDay.date_in(2024), Some(d(2024, 5, 4)));
+    assert_eq!(HolidayId::GreeneryDay.date_in(2000), None);
+    assert_eq!(HolidayId::SportsDay.date_in(1965), None);
+}
@@ -0,0 +1,78 @@
+//! 組込み祝日の規則・根拠法令・適用年のメタデータを検証する。
+
+use jpholiday::{BuiltinHoliday, HolidayKind};
+
+#[test]
+fn every_holiday_has_a_consistent_history() {
+    for b in BuiltinHoliday::ALL {
+        let rules = b.rules();
+        assert!(!rules.is_empty(), "{b:?}");
+        assert_eq!(b.first_year(), rules[0].since);
+        // 年代順で、適用年が重ならない。
+        for pair in rules.windows(2) {
+            let until = pair[0].until.expect("only the last rule may be open-ended");
+            assert!(until < pair[1].since, "{b:?}");
+        }
+        for rule in rules {
+            assert!(!rule.date_rule.is_empty() && !rule.legal_basis.is_empty());
+        }
+
+        // 規則が適用される年にはちょうど 1 日の祝日がある。
+        for year in [1950, 1970, 1990, 2000, 2010, 2019, 2020, 2021, 2030] {
+            let days = jpholiday::year_holidays(year)
+                .into_iter()
+                .filter(|(date, name)| {
+                    jpholiday::holidays(*date)
+                        .iter()
+                        .any(|h| h.kind == HolidayKind::Builtin(b) && &h.name == name)
+                })
+                .count();
+            assert_eq!(
+                days,
+                usize::from(b.rule_for(year).is_some()),
+                "{b:?} in {year}"
+            );
+        }
+    }
+}
+
+#[test]
+fn renamed_and_abolished_holidays() {
+    assert_eq!(BuiltinHoliday::HealthAndSportsDay.last_year(), Some(2019));
+    assert_eq!(BuiltinHoliday::SportsDay.first_year(), 2020);
+    assert_eq!(BuiltinHoliday::SportsDay.successor(), None);
+    // 4 月 29 日の系譜: 天皇誕生日 → みどりの日 → 昭和の日。
+    assert_eq!(
+        BuiltinHoliday::EmperorsBirthday.successor(),
+        Some(BuiltinHoliday::GreeneryDay)
+    );
+    assert_eq!(
+        BuiltinHoliday::GreeneryDay.successor(),
+        Some(BuiltinHoliday::ShowaDay)
+    );
+    assert_eq!(BuiltinHoliday::ShowaDay.successor(), None);
+    // 系譜の名称の並びと一致する。
+    for id in jpholiday::HolidayId::ALL {
+        for pair in id.lineage().windows(2) {
+            assert_eq!(pair[0].holiday.successor(), Some(pair[1].holiday));
+        }
+    }
+    assert_eq!(BuiltinHoliday::NewYear.last_year(), None);
+    assert_eq!(BuiltinHoliday::ExtraHoliday1990.first_year(), 1990);
+    assert_eq!(BuiltinHoliday::ExtraHoliday1990.last_year(), Some(1990));
+}
+
+#[test]
+fn olympic_special_measures() {
+    let sea = BuiltinHoliday::SeaDay;
+    assert_eq!(sea.rule_for(2019).unwrap().date_rule, "7月の第3月曜日");
+    let special = sea.rule_for(2020).unwrap();
+    assert!(special.date_rule.starts_with("7月23日"));
+    assert!(special.legal_basis.contains("特別措置法"));
+    assert_eq!(sea.rule_for(2021).unwrap().since, 2021);
+    assert_eq!(sea.rule_for(2022).unwrap().until, None);
+    assert_eq!(BuiltinHoliday::MountainDay.rule_for(2015), None);
+    // 天皇誕生日は 2019 年にない。
+    assert_eq!(BuiltinHoliday::EmperorsBirthday.rule_for(2019), None);
+    assert_eq!(BuiltinHoliday::EmperorsBirthday.rules().len(), 3);
+}
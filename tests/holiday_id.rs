@@ -0,0 +1,72 @@
+//! 改称された祝日の系譜の識別子を検証する。
+
+use jpholiday::{BuiltinHoliday, Date, HolidayId};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+fn id_on(date: Date) -> Option<HolidayId> {
+    jpholiday::holidays(date).first().and_then(|h| h.id())
+}
+
+#[test]
+fn renamed_holidays_share_an_id() {
+    assert_eq!(id_on(d(2019, 10, 14)), Some(HolidayId::SportsDay)); // 体育の日
+    assert_eq!(id_on(d(2022, 10, 10)), Some(HolidayId::SportsDay)); // スポーツの日
+    assert_eq!(id_on(d(1988, 4, 29)), Some(HolidayId::ShowaDay)); // 天皇誕生日
+    assert_eq!(id_on(d(2006, 4, 29)), Some(HolidayId::ShowaDay)); // みどりの日
+    assert_eq!(id_on(d(2007, 4, 29)), Some(HolidayId::ShowaDay)); // 昭和の日
+    assert_eq!(id_on(d(2007, 5, 4)), Some(HolidayId::GreeneryDay));
+    assert_eq!(id_on(d(2018, 12, 23)), Some(HolidayId::EmperorsBirthday));
+    // 振替休日・国民の休日は対象外。
+    assert_eq!(id_on(d(2019, 5, 6)), None);
+    assert_eq!(id_on(d(2015, 9, 22)), None);
+}
+
+#[test]
+fn lineage_with_rename_dates() {
+    let names: Vec<(&str, Option<Date>)> = HolidayId::ShowaDay
+        .lineage()
+        .iter()
+        .map(|n| (n.name, n.renamed_on))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("天皇誕生日", None),
+            ("みどりの日", Some(d(1989, 2, 17))),
+            ("昭和の日", Some(d(2007, 1, 1))),
+        ]
+    );
+    let sports = HolidayId::SportsDay.lineage();
+    assert_eq!(sports[0].holiday, BuiltinHoliday::HealthAndSportsDay);
+    assert_eq!(sports[1].renamed_on, Some(d(2020, 1, 1)));
+    assert_eq!(HolidayId::SportsDay.name_in(2019), Some("体育の日"));
+    assert_eq!(HolidayId::SportsDay.name_in(1965), None);
+    assert_eq!(HolidayId::SportsDay.to_string(), "スポーツの日");
+}
+
+#[test]
+fn lookup_by_historical_name() {
+    assert_eq!(HolidayId::from_name("体育の日"), [HolidayId::SportsDay]);
+    assert_eq!(
+        HolidayId::from_name("天皇誕生日"),
+        [HolidayId::EmperorsBirthday, HolidayId::ShowaDay]
+    );
+    assert_eq!(
+        HolidayId::from_name("みどりの日"),
+        [HolidayId::ShowaDay, HolidayId::GreeneryDay]
+    );
+    assert!(HolidayId::from_name("存在しない日").is_empty());
+    assert_eq!(
+        HolidayId::from_name_in("みどりの日", 2000),
+        Some(HolidayId::ShowaDay)
+    );
+    assert_eq!(
+        HolidayId::from_name_in("みどりの日", 2010),
+        Some(HolidayId::GreeneryDay)
+    );
+    assert_eq!(HolidayId::from_name_in("スポーツの日", 2019), None);
+}
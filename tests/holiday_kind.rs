@@ -0,0 +1,86 @@
+//! [`HolidayKind`] による祝日種別の判別を検証する。
+
+use jpholiday::{BuiltinHoliday, Date, Holiday, HolidayKind, JPHoliday, OriginalHolidayChecker};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn builtin_kind() {
+    let holidays = jpholiday::holidays(d(2017, 1, 1));
+    assert_eq!(holidays.len(), 1);
+    assert_eq!(
+        holidays[0].kind,
+        HolidayKind::Builtin(BuiltinHoliday::NewYear)
+    );
+    assert_eq!(BuiltinHoliday::NewYear.name(), "元日");
+}
+
+#[test]
+fn substitute_kind_points_at_origin() {
+    let holidays = jpholiday::holidays(d(2017, 1, 2));
+    assert_eq!(
+        holidays[0].kind,
+        HolidayKind::Substitute {
+            original: BuiltinHoliday::NewYear,
+            original_date: d(2017, 1, 1),
+        }
+    );
+
+    // 2008-05-06: 日曜日のみどりの日(5/4)の振替休日（5/5 のこどもの日を越えて振り替わる）。
+    let holidays = jpholiday::holidays(d(2008, 5, 6));
+    assert_eq!(holidays[0].name, "みどりの日 振替休日");
+    assert_eq!(
+        holidays[0].kind,
+        HolidayKind::Substitute {
+            original: BuiltinHoliday::GreeneryDay,
+            original_date: d(2008, 5, 4),
+        }
+    );
+}
+
+#[test]
+fn citizens_kind() {
+    // 2015-09-22: 敬老の日と秋分の日に挟まれた国民の休日。
+    let holidays = jpholiday::holidays(d(2015, 9, 22));
+    assert_eq!(holidays[0].name, "国民の休日");
+    assert_eq!(holidays[0].kind, HolidayKind::Citizens);
+}
+
+#[test]
+fn original_kind() {
+    struct CompanyHoliday;
+    impl OriginalHolidayChecker for CompanyHoliday {
+        fn is_holiday(&self, date: Date) -> bool {
+            date == d(2020, 2, 9)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "特別休暇".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(CompanyHoliday);
+    assert_eq!(jp.holidays(d(2020, 2, 9))[0].kind, HolidayKind::Original);
+}
+
+#[test]
+fn constructor_infers_kind() {
+    assert_eq!(
+        Holiday::new(d(2017, 1, 2), "元日 振替休日").kind,
+        HolidayKind::Substitute {
+            original: BuiltinHoliday::NewYear,
+            original_date: d(2017, 1, 1),
+        }
+    );
+    assert_eq!(
+        Holiday::new(d(2017, 1, 3), "特別休暇").kind,
+        HolidayKind::Original
+    );
+    assert_eq!(
+        Holiday::with_kind(d(2017, 1, 3), "x", HolidayKind::Citizens).kind,
+        HolidayKind::Citizens
+    );
+}
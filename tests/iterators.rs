@@ -0,0 +1,92 @@
+//! 祝日の遅延評価イテレータを検証する。
+
+use jpholiday::{Date, Holiday, JPHoliday, OriginalHolidayChecker};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn forward_take() {
+    let next: Vec<(Date, String)> = jpholiday::holidays_from(d(2017, 1, 1))
+        .take(3)
+        .map(Holiday::into_tuple)
+        .collect();
+    assert_eq!(
+        next,
+        vec![
+            (d(2017, 1, 1), "元日".to_string()),
+            (d(2017, 1, 2), "元日 振替休日".to_string()),
+            (d(2017, 1, 9), "成人の日".to_string()),
+        ]
+    );
+
+    // 数十年先まで Vec を作らずに走査できる。
+    let count = jpholiday::holidays_from(d(2000, 1, 1))
+        .take_while(|h| h.date.year() < 2030)
+        .count();
+    let expected: usize = (2000..2030)
+        .map(|y| jpholiday::year_holidays(y).len())
+        .sum();
+    assert_eq!(count, expected);
+}
+
+#[test]
+fn reverse_goes_into_the_past() {
+    let prev: Vec<Date> = jpholiday::holidays_rev_from(d(2017, 1, 9))
+        .take(3)
+        .map(|h| h.date)
+        .collect();
+    assert_eq!(prev, vec![d(2017, 1, 9), d(2017, 1, 2), d(2017, 1, 1)]);
+
+    // 組込みの祝日のみなら、祝日法施行後最初の祝日（1948-09-23 秋分の日）で終わる。
+    let last = jpholiday::holidays_rev_from(d(1950, 1, 1)).last().unwrap();
+    assert_eq!(last.date, d(1948, 9, 23));
+    assert_eq!(jpholiday::holidays_rev_from(d(1948, 1, 1)).next(), None);
+}
+
+#[test]
+fn bounded_range_matches_vec_api() {
+    let start = d(2017, 1, 1);
+    let end = d(2017, 12, 31);
+    let lazy: Vec<(Date, String)> = jpholiday::holidays_between(start, end)
+        .map(Holiday::into_tuple)
+        .collect();
+    assert_eq!(lazy, jpholiday::year_holidays(2017));
+    assert_eq!(jpholiday::holidays_between(end, start).next(), None);
+    assert_eq!(jpholiday::month_holidays(2017, 5).len(), 3);
+    assert!(jpholiday::month_holidays(2017, 13).is_empty());
+}
+
+#[test]
+fn instance_iterators() {
+    struct Founding;
+    impl OriginalHolidayChecker for Founding {
+        fn is_holiday(&self, date: Date) -> bool {
+            date.year() >= 1900 && (date.month(), date.day()) == (6, 1)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "創業記念日".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    assert_eq!(
+        jp.holidays_from(d(2017, 1, 3)).next().unwrap(),
+        Holiday::new(d(2017, 1, 9), "成人の日")
+    );
+    assert_eq!(
+        jp.holidays_between(d(2017, 5, 1), d(2017, 5, 31)).count(),
+        3
+    );
+
+    // 独自祝日があれば、祝日法施行前まで遡って列挙する（独自祝日が約 10 年途切れたら終わる）。
+    jp.register(Founding);
+    let oldest = jp
+        .holidays_rev_from(d(1949, 1, 1))
+        .filter(|h| h.date.year() < 1948)
+        .last()
+        .unwrap();
+    assert_eq!(oldest.date, d(1900, 6, 1));
+}
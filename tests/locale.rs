@@ -0,0 +1,196 @@
+//! 祝日名の多言語表記（[`Locale`]）を検証する。
+
+use jpholiday::{BuiltinHoliday, Date, HolidayKind, JPHoliday, Locale, OriginalHolidayChecker};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn builtin_names_per_locale() {
+    let b = BuiltinHoliday::RespectForTheAgedDay;
+    assert_eq!(b.localized_name(Locale::Japanese), "敬老の日");
+    assert_eq!(
+        b.localized_name(Locale::English),
+        "Respect for the Aged Day"
+    );
+    assert_eq!(b.localized_name(Locale::Romaji), "Keiro no Hi");
+    assert_eq!(b.localized_name(Locale::Hiragana), "けいろうのひ");
+}
+
+#[test]
+fn names_are_distinct_in_every_locale() {
+    for locale in [
+        Locale::Japanese,
+        Locale::English,
+        Locale::Romaji,
+        Locale::Hiragana,
+    ] {
+        let mut names: Vec<&str> = BuiltinHoliday::ALL
+            .iter()
+            .map(|b| b.localized_name(locale))
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), BuiltinHoliday::ALL.len(), "{locale:?}");
+    }
+    assert_eq!(
+        BuiltinHoliday::ExtraHoliday1990.localized_name(Locale::English),
+        "The Ceremony of the Enthronement of Emperor Akihito"
+    );
+    assert_eq!(
+        BuiltinHoliday::ExtraHoliday2019Oct.localized_name(Locale::English),
+        "The Ceremony of the Enthronement of Emperor Naruhito"
+    );
+}
+
+#[test]
+fn global_api_per_call() {
+    assert_eq!(
+        jpholiday::is_holiday_name_in(d(2017, 1, 1), Locale::English).as_deref(),
+        Some("New Year's Day")
+    );
+    assert_eq!(
+        jpholiday::is_holiday_name_in(d(2017, 1, 2), Locale::Romaji).as_deref(),
+        Some("Ganjitsu Furikae Kyujitsu")
+    );
+    assert_eq!(
+        jpholiday::is_holiday_name_in(d(2015, 9, 22), Locale::Hiragana).as_deref(),
+        Some("こくみんのきゅうじつ")
+    );
+    // 種別は言語によらず同じ。
+    assert_eq!(
+        jpholiday::holidays_in(d(2017, 1, 1), Locale::English)[0].kind,
+        HolidayKind::Builtin(BuiltinHoliday::NewYear)
+    );
+    assert_eq!(
+        jpholiday::is_holiday_name_in(d(2017, 1, 3), Locale::English),
+        None
+    );
+}
+
+#[test]
+fn instance_locale() {
+    let mut jp = JPHoliday::with_locale(Locale::English);
+    assert_eq!(jp.locale(), Locale::English);
+    assert_eq!(
+        jp.is_holiday_name(d(2015, 9, 22)).as_deref(),
+        Some("Citizens' Holiday")
+    );
+    // 呼び出し単位の指定はインスタンスの言語より優先される。
+    assert_eq!(
+        jp.is_holiday_name_in(d(2015, 9, 22), Locale::Japanese)
+            .as_deref(),
+        Some("国民の休日")
+    );
+
+    jp.set_locale(Locale::Japanese);
+    assert_eq!(
+        jp.is_holiday_name(d(2015, 9, 22)).as_deref(),
+        Some("国民の休日")
+    );
+    assert_eq!(JPHoliday::new().locale(), Locale::Japanese);
+}
+
+#[test]
+fn kind_localized_name() {
+    let kind = jpholiday::holidays(d(2017, 1, 2))[0].kind;
+    assert_eq!(
+        kind.localized_name(Locale::English).as_deref(),
+        Some("New Year's Day (Substitute Holiday)")
+    );
+    assert_eq!(HolidayKind::Original.localized_name(Locale::English), None);
+}
+
+#[test]
+fn original_checker_localized_name() {
+    struct FoundingDay;
+    impl OriginalHolidayChecker for FoundingDay {
+        fn is_holiday(&self, date: Date) -> bool {
+            date == d(2020, 2, 10)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "創業記念日".to_string()
+        }
+        fn localized_holiday_name(&self, date: Date, locale: Locale) -> String {
+            match locale {
+                Locale::English => "Founding Day".to_string(),
+                _ => self.holiday_name(date),
+            }
+        }
+    }
+
+    struct JapaneseOnly;
+    impl OriginalHolidayChecker for JapaneseOnly {
+        fn is_holiday(&self, date: Date) -> bool {
+            date == d(2020, 2, 12)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "特別休暇".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::with_locale(Locale::English);
+    jp.register(FoundingDay);
+    jp.register(JapaneseOnly);
+    assert_eq!(
+        jp.is_holiday_name(d(2020, 2, 10)).as_deref(),
+        Some("Founding Day")
+    );
+    assert_eq!(
+        jp.is_holiday_name_in(d(2020, 2, 10), Locale::Romaji)
+            .as_deref(),
+        Some("創業記念日")
+    );
+    // 多言語名を実装しないチェッカーは既定で日本語名を返す。
+    assert_eq!(
+        jp.is_holiday_name(d(2020, 2, 12)).as_deref(),
+        Some("特別休暇")
+    );
+}
+
+#[test]
+fn global_list_apis_in_locale() {
+    let year = jpholiday::year_holidays_in(2017, Locale::English);
+    assert_eq!(year[0], (d(2017, 1, 1), "New Year's Day".to_string()));
+    assert_eq!(year.len(), jpholiday::year_holidays(2017).len());
+
+    let may = jpholiday::month_holidays_in(2017, 5, Locale::Hiragana);
+    assert_eq!(may[2].1, "こどものひ");
+    assert!(jpholiday::month_holidays_in(2017, 13, Locale::English).is_empty());
+
+    let between = jpholiday::between_in(d(2017, 1, 1), d(2017, 1, 2), Locale::Romaji);
+    assert_eq!(between[1].1, "Ganjitsu Furikae Kyujitsu");
+
+    let names: Vec<String> = jpholiday::holidays_from(d(1993, 6, 1))
+        .with_locale(Locale::Romaji)
+        .take(1)
+        .map(|h| h.name)
+        .collect();
+    assert_eq!(names, ["Kotaishi Kotaishi Naruhito Shinno no Kekkon no Gi"]);
+
+    // インスタンスの既定言語より優先する。
+    let jp = JPHoliday::with_locale(Locale::English);
+    let first = jp
+        .holidays_from(d(2024, 1, 1))
+        .with_locale(Locale::Japanese)
+        .next()
+        .unwrap();
+    assert_eq!(first.name, "元日");
+}
+
+#[test]
+fn infer_kind_from_localized_name() {
+    assert_eq!(
+        jpholiday::Holiday::new(d(2017, 1, 2), "New Year's Day (Substitute Holiday)").kind,
+        HolidayKind::Substitute {
+            original: BuiltinHoliday::NewYear,
+            original_date: d(2017, 1, 1),
+        }
+    );
+    assert_eq!(
+        jpholiday::Holiday::new(d(2017, 1, 1), "がんじつ").kind,
+        HolidayKind::Builtin(BuiltinHoliday::NewYear)
+    );
+}
@@ -0,0 +1,112 @@
+//! 旧暦との相互変換を検証する。
+
+use jpholiday::{Date, DateError, LunisolarDate};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[inline]
+fn lunar(year: i32, month: u32, leap: bool, day: u32) -> LunisolarDate {
+    LunisolarDate {
+        year,
+        month,
+        leap,
+        day,
+    }
+}
+
+#[test]
+fn lunar_new_year() {
+    assert_eq!(
+        d(2024, 2, 10).to_lunisolar(),
+        Some(lunar(2024, 1, false, 1))
+    );
+    assert_eq!(
+        d(2024, 2, 9).to_lunisolar(),
+        Some(lunar(2023, 12, false, 30))
+    );
+    assert_eq!(
+        d(1900, 1, 31).to_lunisolar(),
+        Some(lunar(1900, 1, false, 1))
+    );
+    assert_eq!(d(2100, 2, 9).to_lunisolar(), Some(lunar(2100, 1, false, 1)));
+}
+
+#[test]
+fn leap_months() {
+    // 各年の閏月の初日。
+    let cases = [
+        (lunar(2012, 3, true, 1), d(2012, 4, 21)),
+        (lunar(2014, 9, true, 1), d(2014, 10, 24)),
+        (lunar(2017, 5, true, 1), d(2017, 6, 24)),
+        (lunar(2020, 4, true, 1), d(2020, 5, 23)),
+        (lunar(2023, 2, true, 1), d(2023, 3, 22)),
+        (lunar(2025, 6, true, 1), d(2025, 7, 25)),
+        // 2033 年問題: 天保暦の規則では決まらず、閏 11 月とするのが通例。
+        (lunar(2033, 11, true, 1), d(2033, 12, 22)),
+    ];
+    for (lunisolar, date) in cases {
+        assert_eq!(lunisolar.to_date(), Ok(date), "{lunisolar}");
+        assert_eq!(date.to_lunisolar(), Some(lunisolar), "{date}");
+    }
+}
+
+#[test]
+fn invalid_dates() {
+    // 2024 年に閏月はない。
+    assert_eq!(
+        Date::from_lunisolar(2024, 5, true, 1),
+        Err(DateError::InvalidLunisolarDate {
+            year: 2024,
+            month: 5,
+            leap: true,
+            day: 1,
+        })
+    );
+    // 2024 年 1 月は小の月（29 日まで）。
+    assert!(Date::from_lunisolar(2024, 1, false, 29).is_ok());
+    assert!(Date::from_lunisolar(2024, 1, false, 30).is_err());
+    assert!(Date::from_lunisolar(2024, 13, false, 1).is_err());
+    assert!(Date::from_lunisolar(2024, 1, false, 0).is_err());
+    // 新暦で 1900〜2100 年の範囲外。
+    assert!(Date::from_lunisolar(1899, 1, false, 1).is_err());
+    assert_eq!(Date::from_lunisolar(1899, 12, false, 2), Ok(d(1900, 1, 2)));
+    assert!(Date::from_lunisolar(2100, 12, false, 29).is_err());
+    assert_eq!(d(1899, 12, 31).to_lunisolar(), None);
+    assert_eq!(d(2101, 1, 1).to_lunisolar(), None);
+}
+
+#[test]
+fn round_trip_and_continuity() {
+    let mut date = d(1900, 1, 1);
+    let mut previous = date.to_lunisolar().unwrap();
+    let mut leap_months = 0;
+    while date < d(2100, 12, 31) {
+        date = date.add_days(3);
+        let current = date.to_lunisolar().unwrap();
+        assert_eq!(current.to_date(), Ok(date), "{date}");
+        assert!(
+            current > previous || current.leap && !previous.leap,
+            "{date}"
+        );
+        if current.leap && current.day <= 3 {
+            leap_months += 1;
+        }
+        previous = current;
+    }
+    // 19 年に 7 回の閏月。
+    assert!((72..=76).contains(&leap_months), "{leap_months}");
+}
+
+#[test]
+fn display() {
+    assert_eq!(lunar(2023, 2, true, 15).to_string(), "2023年閏2月15日");
+    assert_eq!(
+        Date::from_lunisolar(2023, 2, false, 31)
+            .unwrap_err()
+            .to_string(),
+        "invalid lunisolar date: 2023-02-31"
+    );
+}
@@ -0,0 +1,173 @@
+//! 前後の祝日の探索を検証する。
+
+use jpholiday::{Date, HolidayKind, JPHoliday, OriginalHolidayChecker};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn next_includes_substitute_and_citizens_holidays() {
+    let next = jpholiday::next_holiday(d(2017, 1, 1)).unwrap();
+    assert_eq!(
+        (next.date, next.name.as_str()),
+        (d(2017, 1, 2), "元日 振替休日")
+    );
+
+    let next = jpholiday::next_holiday(d(2015, 9, 21)).unwrap();
+    assert_eq!(next.date, d(2015, 9, 22));
+    assert_eq!(next.kind, HolidayKind::Citizens);
+
+    // 年をまたぐ。
+    let next = jpholiday::next_holiday(d(2017, 12, 23)).unwrap();
+    assert_eq!(next.date, d(2018, 1, 1));
+}
+
+#[test]
+fn previous_holiday() {
+    let prev = jpholiday::previous_holiday(d(2017, 1, 9)).unwrap();
+    assert_eq!(prev.date, d(2017, 1, 2));
+    let prev = jpholiday::previous_holiday(d(2018, 1, 1)).unwrap();
+    assert_eq!(
+        (prev.date, prev.name.as_str()),
+        (d(2017, 12, 23), "天皇誕生日")
+    );
+    assert_eq!(jpholiday::previous_holiday(d(1948, 9, 23)), None);
+}
+
+#[test]
+fn days_until() {
+    assert_eq!(jpholiday::days_until_next_holiday(d(2024, 1, 2)), Some(6));
+    assert_eq!(jpholiday::days_until_next_holiday(d(2024, 1, 7)), Some(1));
+    // 祝日当日は「次の」祝日までの日数。
+    assert_eq!(jpholiday::days_until_next_holiday(d(2024, 1, 1)), Some(7));
+}
+
+/// `start` から `end` までの各日について、1 日ずつ判定した結果と探索結果が一致することを確認する。
+fn assert_matches_scan(jp: &JPHoliday, start: Date, end: Date) {
+    let naive_next = |from: Date| {
+        let mut date = from.succ();
+        while !jp.is_holiday(date) {
+            date = date.succ();
+        }
+        date
+    };
+    let naive_previous = |from: Date| {
+        let mut date = from.pred();
+        while date.year() >= 1948 {
+            if jp.is_holiday(date) {
+                return Some(date);
+            }
+            date = date.pred();
+        }
+        None
+    };
+
+    let mut next = naive_next(start);
+    let mut previous = naive_previous(start);
+    let mut date = start;
+    while date <= end {
+        if date == next {
+            next = naive_next(date);
+        }
+        assert_eq!(
+            jp.next_holiday(date).map(|h| h.date),
+            Some(next),
+            "after {date}"
+        );
+        assert_eq!(
+            jp.previous_holiday(date).map(|h| h.date),
+            previous,
+            "before {date}"
+        );
+        if jp.is_holiday(date) {
+            previous = Some(date);
+        }
+        date = date.succ();
+    }
+}
+
+#[test]
+fn matches_day_by_day_scan() {
+    let jp = JPHoliday::new();
+    // 祝日法施行・振替休日導入（1973 年）の前後。
+    assert_matches_scan(&jp, d(1948, 1, 1), d(1975, 12, 31));
+    // 国民の休日・五輪特例を含む近年。
+    assert_matches_scan(&jp, d(2005, 1, 1), d(2027, 12, 31));
+}
+
+#[test]
+fn original_holidays_are_found() {
+    struct Founding;
+    impl OriginalHolidayChecker for Founding {
+        fn is_holiday(&self, date: Date) -> bool {
+            date == d(2024, 6, 14) || date == d(1900, 1, 5)
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            "創立記念日".to_string()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(Founding);
+    assert_eq!(jp.next_holiday(d(2024, 5, 31)).unwrap().name, "創立記念日");
+    assert_eq!(
+        jp.previous_holiday(d(2024, 7, 1)).unwrap().date,
+        d(2024, 6, 14)
+    );
+    assert_eq!(
+        jp.previous_holiday(d(1909, 1, 1)).unwrap().date,
+        d(1900, 1, 5)
+    );
+    assert_eq!(jp.days_until_next_holiday(d(2024, 6, 1)), Some(13));
+}
+
+#[test]
+fn backward_search_of_original_holidays_is_bounded() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Never;
+    impl OriginalHolidayChecker for Never {
+        fn is_holiday(&self, _date: Date) -> bool {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+        fn holiday_name(&self, _date: Date) -> String {
+            String::new()
+        }
+    }
+
+    let mut jp = JPHoliday::new();
+    jp.register(Never);
+    // 祝日法の施行前は独自チェッカーを 1 日ずつ判定するが、約 10 年で打ち切る。
+    assert_eq!(jp.previous_holiday(d(1948, 1, 1)), None);
+    let calls = CALLS.load(Ordering::Relaxed);
+    assert!((3660..4000).contains(&calls), "{calls}");
+
+    // 施行後は組込み祝日で止まる。
+    assert_eq!(
+        jp.previous_holiday(d(1949, 1, 2)).unwrap().date,
+        d(1949, 1, 1)
+    );
+}
+
+#[test]
+fn search_at_year_boundaries() {
+    // `Date` が表現できる最後の年をまたいでも候補年の計算が溢れない。
+    assert_eq!(jpholiday::next_holiday(d(i32::MAX, 12, 30)), None);
+    assert_eq!(jpholiday::next_holiday(d(i32::MAX, 12, 31)), None);
+    assert_eq!(jpholiday::holidays_from(d(i32::MAX, 12, 1)).count(), 0);
+    assert!(!jpholiday::is_holiday(d(i32::MAX, 3, 20)));
+
+    assert_eq!(jpholiday::previous_holiday(d(i32::MIN, 1, 2)), None);
+    assert_eq!(
+        jpholiday::holidays_from(d(i32::MIN, 1, 1))
+            .next()
+            .unwrap()
+            .date,
+        d(1948, 9, 23)
+    );
+}
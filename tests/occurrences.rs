@@ -0,0 +1,88 @@
+//! 祝日ごとの発生日の系列を検証する。
+
+use jpholiday::{BuiltinHoliday, Date, HolidayId, HolidayKind, Weekday};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn sports_day_series() {
+    let series: Vec<_> = BuiltinHoliday::SportsDay.occurrences(2020..=2100).collect();
+    assert_eq!(series.len(), 81);
+    assert_eq!(series[0].date, d(2020, 7, 24));
+    assert_eq!(series[0].weekday, Weekday::Friday);
+    assert_eq!(series[1].date, d(2021, 7, 23));
+    // 2022 年以降は 10 月の第 2 月曜日で、振替休日を生じさせない。
+    assert!(
+        series[2..]
+            .iter()
+            .all(|o| o.weekday == Weekday::Monday && o.substitute.is_none())
+    );
+    // 逆順にも列挙できる。
+    let last = BuiltinHoliday::SportsDay
+        .occurrences(2020..=2100)
+        .next_back()
+        .unwrap();
+    assert_eq!(last.date.year(), 2100);
+}
+
+#[test]
+fn substitutes_caused_by_constitution_day() {
+    let substitutes: Vec<(Date, Date)> = BuiltinHoliday::ConstitutionMemorialDay
+        .occurrences(1970..=2030)
+        .filter_map(|o| o.substitute.map(|s| (o.date, s)))
+        .collect();
+    // 1973 年の振替休日制度の導入前（1970 年）は生じない。
+    // 2007 年以降は 5/4・5/5 を越えて 5/6 に振り替わる。
+    assert_eq!(
+        substitutes,
+        [
+            (d(1981, 5, 3), d(1981, 5, 4)),
+            (d(1987, 5, 3), d(1987, 5, 4)),
+            (d(1992, 5, 3), d(1992, 5, 4)),
+            (d(1998, 5, 3), d(1998, 5, 4)),
+            (d(2009, 5, 3), d(2009, 5, 6)),
+            (d(2015, 5, 3), d(2015, 5, 6)),
+            (d(2020, 5, 3), d(2020, 5, 6)),
+            (d(2026, 5, 3), d(2026, 5, 6)),
+        ]
+    );
+    for (_, substitute) in &substitutes {
+        let kinds: Vec<HolidayKind> = jpholiday::holidays(*substitute)
+            .iter()
+            .map(|h| h.kind)
+            .collect();
+        assert!(kinds.iter().any(|k| matches!(
+            k,
+            HolidayKind::Substitute {
+                original: BuiltinHoliday::ConstitutionMemorialDay,
+                ..
+            }
+        )));
+    }
+}
+
+#[test]
+fn series_by_lineage_spans_renames() {
+    let series: Vec<_> = HolidayId::ShowaDay.occurrences(1987..=2008).collect();
+    assert_eq!(series.len(), 22);
+    assert_eq!(series[0].holiday, BuiltinHoliday::EmperorsBirthday);
+    assert_eq!(series[2].holiday, BuiltinHoliday::GreeneryDay);
+    assert_eq!(series[20].holiday, BuiltinHoliday::ShowaDay);
+    assert!(
+        series
+            .iter()
+            .all(|o| (o.date.month(), o.date.day()) == (4, 29))
+    );
+    // 1990-04-29 (日) のみどりの日は 4/30 に振り替わる。
+    assert_eq!(series[3].substitute, Some(d(1990, 4, 30)));
+
+    // 天皇誕生日がない 2019 年は飛ばす。
+    let years: Vec<i32> = HolidayId::EmperorsBirthday
+        .occurrences(2018..=2020)
+        .map(|o| o.date.year())
+        .collect();
+    assert_eq!(years, [2018, 2020]);
+}
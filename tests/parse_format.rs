@@ -0,0 +1,125 @@
+//! 日付の文字列解析と書式化を検証する。
+
+use jpholiday::{Date, DateError};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn parse_supported_formats() {
+    let cases = [
+        ("2024-01-01", d(2024, 1, 1)),
+        ("2024-1-9", d(2024, 1, 9)),
+        ("20240101", d(2024, 1, 1)),
+        ("2024/01/01", d(2024, 1, 1)),
+        ("2024/12/31", d(2024, 12, 31)),
+        ("2024年1月1日", d(2024, 1, 1)),
+        ("２０２４年１２月２５日", d(2024, 12, 25)),
+        ("令和6年1月1日", d(2024, 1, 1)),
+        ("令和元年5月1日", d(2019, 5, 1)),
+        ("平成31年4月30日", d(2019, 4, 30)),
+        ("昭和64年1月7日", d(1989, 1, 7)),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(Date::parse(input), Ok(expected), "{input}");
+        assert_eq!(input.parse::<Date>(), Ok(expected), "{input}");
+    }
+}
+
+#[test]
+fn parse_errors_report_position() {
+    assert_eq!(
+        Date::parse("2024-01/01"),
+        Err(DateError::UnexpectedCharacter {
+            position: 7,
+            found: '/'
+        })
+    );
+    assert_eq!(
+        Date::parse("2024-01-"),
+        Err(DateError::UnexpectedEnd { position: 8 })
+    );
+    assert_eq!(
+        Date::parse("2024年1月1"),
+        Err(DateError::UnexpectedEnd { position: 8 })
+    );
+    assert_eq!(
+        Date::parse("x2024-01-01"),
+        Err(DateError::UnexpectedCharacter {
+            position: 0,
+            found: 'x'
+        })
+    );
+    assert_eq!(
+        Date::parse("2024-01-01 "),
+        Err(DateError::UnexpectedCharacter {
+            position: 10,
+            found: ' '
+        })
+    );
+    assert_eq!(
+        Date::parse("慶応4年1月1日"),
+        Err(DateError::UnexpectedCharacter {
+            position: 0,
+            found: '慶'
+        })
+    );
+    assert_eq!(
+        Date::parse("9999999999-01-01"),
+        Err(DateError::InvalidNumber { position: 0 })
+    );
+    assert_eq!(
+        Date::parse(""),
+        Err(DateError::UnexpectedEnd { position: 0 })
+    );
+    assert_eq!(
+        Date::parse("2024-01-01 ").unwrap_err().to_string(),
+        "unexpected character ' ' at position 10"
+    );
+}
+
+#[test]
+fn parse_semantic_errors() {
+    assert!(matches!(
+        Date::parse("2023-02-29"),
+        Err(DateError::InvalidDate { .. })
+    ));
+    assert!(matches!(
+        Date::parse("平成31年5月1日"),
+        Err(DateError::OutOfEra { .. })
+    ));
+}
+
+#[test]
+fn format_patterns() {
+    let date = d(2024, 1, 8);
+    assert_eq!(date.format("%Y-%m-%d"), date.to_string());
+    assert_eq!(date.format("%Y年%-m月%-d日(%a)"), "2024年1月8日(月)");
+    assert_eq!(date.format("%A"), "月曜日");
+    assert_eq!(date.format("%E%J年%m月%d日"), "令和6年01月08日");
+    assert_eq!(date.format("%E%KJ年%Km月%Kd日"), "令和六年一月八日");
+    assert_eq!(date.format("%y%%"), "24%");
+    assert_eq!(date.format("%Q %"), "%Q %");
+
+    let gannen = d(2019, 5, 1);
+    assert_eq!(gannen.format("%E%J年"), "令和元年");
+    assert_eq!(gannen.format("%E%KJ年"), "令和元年");
+    assert_eq!(
+        d(1988, 12, 23).format("%E%KJ年%Km月%Kd日"),
+        "昭和六十三年十二月二十三日"
+    );
+    // 明治より前は元号を出力しない。
+    assert_eq!(d(1800, 1, 1).format("[%E%J]"), "[]");
+}
+
+#[test]
+fn format_then_parse_roundtrip() {
+    let mut date = d(2019, 1, 1);
+    while date < d(2020, 1, 1) {
+        assert_eq!(Date::parse(&date.format("%E%J年%-m月%-d日")), Ok(date));
+        assert_eq!(Date::parse(&date.format("%Y%m%d")), Ok(date));
+        date = date.succ();
+    }
+}
@@ -0,0 +1,134 @@
+//! 民法の期間計算（第 140 条〜第 143 条）を検証する。
+
+use jpholiday::{Date, JPHoliday, Period};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn days_exclude_initial_day() {
+    let jp = JPHoliday::new();
+    // 2024-03-01 から 10 日: 3/2 起算で 3/11 (月) に満了する。
+    let r = jp.period_deadline(d(2024, 3, 1), Period::Days(10)).unwrap();
+    assert_eq!(r.initial_day, d(2024, 3, 2));
+    assert_eq!(r.period_end, d(2024, 3, 11));
+    assert_eq!(r.deadline, d(2024, 3, 11));
+    assert!(!r.is_extended());
+    assert_eq!(r.explanation.len(), 2);
+}
+
+#[test]
+fn months_and_years_use_corresponding_day() {
+    let jp = JPHoliday::new();
+    // 応当日の前日。
+    assert_eq!(
+        jp.period_deadline(d(2024, 3, 15), Period::Months(1))
+            .unwrap()
+            .period_end,
+        d(2024, 4, 15)
+    );
+    // 応当日（2/31）がない場合は月末。
+    assert_eq!(
+        jp.period_deadline(d(2023, 1, 30), Period::Months(1))
+            .unwrap()
+            .period_end,
+        d(2023, 2, 28)
+    );
+    // 月末の出来事は翌月 1 日から起算し、最後の月の末日に満了する。
+    assert_eq!(
+        jp.period_deadline(d(2024, 1, 31), Period::Months(1))
+            .unwrap()
+            .period_end,
+        d(2024, 2, 29)
+    );
+    // 年をまたぐ月の期間。
+    assert_eq!(
+        jp.period_deadline(d(2024, 11, 10), Period::Months(3))
+            .unwrap()
+            .period_end,
+        d(2025, 2, 10)
+    );
+    // 閏日を起算日とする 1 年は、翌年 2 月の末日に満了する。
+    assert_eq!(
+        jp.period_deadline(d(2024, 2, 28), Period::Years(1))
+            .unwrap()
+            .period_end,
+        d(2025, 2, 28)
+    );
+    assert_eq!(
+        jp.period_deadline(d(2024, 4, 1), Period::Years(2))
+            .unwrap()
+            .period_end,
+        d(2026, 4, 1)
+    );
+    assert_eq!(
+        jp.period_deadline(d(2024, 1, 23), Period::Weeks(2))
+            .unwrap()
+            .period_end,
+        d(2024, 2, 6)
+    );
+}
+
+#[test]
+fn last_day_on_holiday_is_extended() {
+    let jp = JPHoliday::new();
+    // 末日 2024-05-03 (金・憲法記念日) から 5/4〜5/6 の休日を経て 5/7 に満了する。
+    let r = jp
+        .period_deadline(d(2024, 4, 3), Period::Months(1))
+        .unwrap();
+    assert_eq!(r.period_end, d(2024, 5, 3));
+    assert_eq!(r.deadline, d(2024, 5, 7));
+    assert!(r.is_extended());
+    assert!(r.explanation[2].contains("憲法記念日"));
+
+    // 末日が日曜日。
+    let r = jp.period_deadline(d(2024, 6, 2), Period::Weeks(1)).unwrap();
+    assert_eq!(r.period_end, d(2024, 6, 9));
+    assert_eq!(r.deadline, d(2024, 6, 10));
+    assert!(r.explanation[2].contains("日曜日"));
+
+    // 行政機関の休日（年末年始）も延長の対象になる。
+    let gov = JPHoliday::government();
+    let r = gov
+        .period_deadline(d(2024, 12, 19), Period::Days(10))
+        .unwrap();
+    assert_eq!(r.period_end, d(2024, 12, 29));
+    assert_eq!(r.deadline, d(2025, 1, 6));
+}
+
+#[test]
+fn period_display() {
+    assert_eq!(Period::Days(3).to_string(), "3日");
+    assert_eq!(Period::Weeks(2).to_string(), "2週間");
+    assert_eq!(Period::Months(6).to_string(), "6か月");
+    assert_eq!(Period::Years(1).to_string(), "1年");
+}
+
+#[test]
+fn out_of_range_period_is_none() {
+    let jp = JPHoliday::new();
+    assert!(
+        jp.period_deadline(d(i32::MAX, 6, 1), Period::Years(1))
+            .is_none()
+    );
+    assert!(
+        jp.period_deadline(d(i32::MAX, 12, 1), Period::Days(31))
+            .is_none()
+    );
+    assert!(
+        jp.period_deadline(d(i32::MAX, 12, 31), Period::Days(0))
+            .is_none()
+    );
+    assert!(
+        jp.period_deadline(d(2024, 1, 1), Period::Years(u32::MAX))
+            .is_none()
+    );
+    assert_eq!(
+        jp.period_deadline(d(i32::MAX, 11, 1), Period::Days(30))
+            .unwrap()
+            .period_end,
+        d(i32::MAX, 12, 1)
+    );
+}
@@ -0,0 +1,86 @@
+//! 休業日の調整（[`RollConvention`]）を検証する。
+
+use jpholiday::{Date, JPHoliday, RollConvention, Weekend};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn business_day_is_unchanged() {
+    for convention in [
+        RollConvention::Following,
+        RollConvention::ModifiedFollowing,
+        RollConvention::Preceding,
+        RollConvention::ModifiedPreceding,
+    ] {
+        assert_eq!(
+            jpholiday::adjust(d(2024, 1, 10), convention),
+            d(2024, 1, 10)
+        );
+    }
+}
+
+#[test]
+fn following_and_preceding() {
+    // 2024-01-08 は成人の日（月曜日）。
+    assert_eq!(
+        jpholiday::adjust(d(2024, 1, 8), RollConvention::Following),
+        d(2024, 1, 9)
+    );
+    assert_eq!(
+        jpholiday::adjust(d(2024, 1, 8), RollConvention::Preceding),
+        d(2024, 1, 5)
+    );
+}
+
+#[test]
+fn modified_conventions_stay_in_month() {
+    // 2024-08-31 は土曜日。翌営業日は 9/2 なので月内の 8/30 に戻す。
+    assert_eq!(
+        jpholiday::adjust(d(2024, 8, 31), RollConvention::Following),
+        d(2024, 9, 2)
+    );
+    assert_eq!(
+        jpholiday::adjust(d(2024, 8, 31), RollConvention::ModifiedFollowing),
+        d(2024, 8, 30)
+    );
+    // 2024-06-01 は土曜日。前営業日は 5/31 なので月内の 6/3 に進める。
+    assert_eq!(
+        jpholiday::adjust(d(2024, 6, 1), RollConvention::Preceding),
+        d(2024, 5, 31)
+    );
+    assert_eq!(
+        jpholiday::adjust(d(2024, 6, 1), RollConvention::ModifiedPreceding),
+        d(2024, 6, 3)
+    );
+}
+
+#[test]
+fn end_of_month() {
+    // 2024-03-31 は日曜日、3/30 は土曜日。
+    assert_eq!(
+        jpholiday::adjust(d(2024, 3, 5), RollConvention::EndOfMonth),
+        d(2024, 3, 29)
+    );
+    assert_eq!(
+        jpholiday::adjust(d(2024, 2, 1), RollConvention::EndOfMonth),
+        d(2024, 2, 29)
+    );
+}
+
+#[test]
+fn instance_uses_its_weekend() {
+    let mut jp = JPHoliday::new();
+    jp.set_weekend(Weekend::SUNDAY);
+    // 2024-05-25 は土曜日だが、日曜日のみ休業なら営業日。
+    assert_eq!(
+        jp.adjust(d(2024, 5, 25), RollConvention::Preceding),
+        d(2024, 5, 25)
+    );
+    assert_eq!(
+        JPHoliday::new().adjust(d(2024, 5, 25), RollConvention::Preceding),
+        d(2024, 5, 24)
+    );
+}
@@ -0,0 +1,92 @@
+//! 雑節の日付と期間を検証する。
+
+use jpholiday::{Date, SeasonalDay, SolarTerm};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn seasonal_days_2024() {
+    use SeasonalDay::*;
+    assert_eq!(
+        jpholiday::seasonal_days(2024).unwrap(),
+        [
+            (WinterDoyo, d(2024, 1, 18)),
+            (Setsubun, d(2024, 2, 3)),
+            (SpringHiganStart, d(2024, 3, 17)),
+            (SpringHiganEnd, d(2024, 3, 23)),
+            (SpringDoyo, d(2024, 4, 16)),
+            (Hachijuhachiya, d(2024, 5, 1)),
+            (Nyubai, d(2024, 6, 10)),
+            (Hangesho, d(2024, 7, 1)),
+            (SummerDoyo, d(2024, 7, 19)),
+            (Nihyakutoka, d(2024, 8, 31)),
+            (Nihyakuhatsuka, d(2024, 9, 10)),
+            (AutumnHiganStart, d(2024, 9, 19)),
+            (AutumnHiganEnd, d(2024, 9, 25)),
+            (AutumnDoyo, d(2024, 10, 20)),
+        ]
+    );
+}
+
+#[test]
+fn seasonal_days_2023() {
+    // 節分が 2/3、入梅が 6/11、半夏生が 7/2 の年。
+    assert_eq!(SeasonalDay::Setsubun.in_year(2023).unwrap(), d(2023, 2, 3));
+    assert_eq!(SeasonalDay::Nyubai.in_year(2023).unwrap(), d(2023, 6, 11));
+    assert_eq!(SeasonalDay::Hangesho.in_year(2023).unwrap(), d(2023, 7, 2));
+    assert_eq!(
+        SeasonalDay::AutumnDoyo.in_year(2023).unwrap(),
+        d(2023, 10, 21)
+    );
+}
+
+#[test]
+fn derived_from_solar_terms() {
+    for year in 1950..=2100 {
+        let risshun = SolarTerm::StartOfSpring.in_year(year).unwrap().date;
+        assert_eq!(SeasonalDay::Setsubun.in_year(year).unwrap(), risshun.pred());
+        assert_eq!(
+            SeasonalDay::Hachijuhachiya.in_year(year).unwrap(),
+            risshun.add_days(87)
+        );
+        assert_eq!(
+            SeasonalDay::Nihyakutoka.in_year(year).unwrap(),
+            risshun.add_days(209)
+        );
+        // 彼岸の中日は春分の日・秋分の日（祝日）と一致する。
+        let (start, end) = SeasonalDay::AutumnHiganStart.period_in(year).unwrap();
+        assert_eq!(end.to_ordinal() - start.to_ordinal(), 6);
+        assert!(jpholiday::is_holiday(start.add_days(3)), "{year}");
+    }
+}
+
+#[test]
+fn doyo_periods() {
+    assert_eq!(
+        SeasonalDay::WinterDoyo.period_in(2025),
+        Some((d(2025, 1, 17), d(2025, 2, 2)))
+    );
+    assert_eq!(
+        SeasonalDay::AutumnDoyo.period_in(2024),
+        Some((d(2024, 10, 20), d(2024, 11, 6)))
+    );
+    assert_eq!(SeasonalDay::Nyubai.period_in(2024), None);
+    assert_eq!(SeasonalDay::SpringHiganEnd.period_in(2024), None);
+    assert_eq!(
+        SeasonalDay::from_name("半夏生"),
+        Some(SeasonalDay::Hangesho)
+    );
+    assert_eq!(SeasonalDay::Nihyakuhatsuka.to_string(), "二百二十日");
+}
+
+#[test]
+fn outside_supported_years() {
+    assert_eq!(jpholiday::seasonal_days(1872), None);
+    assert_eq!(jpholiday::seasonal_days(3001), None);
+    assert_eq!(SeasonalDay::Setsubun.in_year(1500), None);
+    assert_eq!(SeasonalDay::SummerDoyo.period_in(i32::MAX), None);
+    assert!(jpholiday::seasonal_days(1873).is_some());
+}
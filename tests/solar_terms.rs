@@ -0,0 +1,98 @@
+//! 二十四節気の日付と瞬刻を検証する。
+
+use jpholiday::{Date, SolarTerm};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn solar_terms_2024() {
+    // 国立天文台の暦要項（2024 年）による日付。
+    let expected = [
+        d(2024, 1, 6),
+        d(2024, 1, 20),
+        d(2024, 2, 4),
+        d(2024, 2, 19),
+        d(2024, 3, 5),
+        d(2024, 3, 20),
+        d(2024, 4, 4),
+        d(2024, 4, 19),
+        d(2024, 5, 5),
+        d(2024, 5, 20),
+        d(2024, 6, 5),
+        d(2024, 6, 21),
+        d(2024, 7, 6),
+        d(2024, 7, 22),
+        d(2024, 8, 7),
+        d(2024, 8, 22),
+        d(2024, 9, 7),
+        d(2024, 9, 22),
+        d(2024, 10, 8),
+        d(2024, 10, 23),
+        d(2024, 11, 7),
+        d(2024, 11, 22),
+        d(2024, 12, 7),
+        d(2024, 12, 21),
+    ];
+    let terms = jpholiday::solar_terms(2024).unwrap();
+    let dates: Vec<Date> = terms.iter().map(|t| t.date).collect();
+    assert_eq!(dates, expected);
+    let order: Vec<SolarTerm> = terms.iter().map(|t| t.term).collect();
+    assert_eq!(order, SolarTerm::ALL);
+}
+
+/// 瞬刻の 0 時からの分数。
+fn minutes(t: &jpholiday::astronomy::JulianDateTime) -> i64 {
+    t.hour * 60 + t.minute
+}
+
+#[test]
+fn instants_in_jst() {
+    // 簡略式による計算のため、公表値（分単位）との差は数分以内とする。
+    // 2025 年の立春は 2/3 23:10（UTC では同日 14:10）。
+    let risshun = SolarTerm::StartOfSpring.in_year(2025).unwrap();
+    assert_eq!(risshun.date, d(2025, 2, 3));
+    assert!((minutes(&risshun.time) - (23 * 60 + 10)).abs() <= 3);
+
+    // 2024 年の夏至は 6/21 05:51。
+    let geshi = SolarTerm::SummerSolstice.in_year(2024).unwrap();
+    assert_eq!(geshi.date, d(2024, 6, 21));
+    assert!((minutes(&geshi.time) - (5 * 60 + 51)).abs() <= 3);
+}
+
+#[test]
+fn equinoxes_match_holidays() {
+    for year in 1948..=2100 {
+        let vernal = SolarTerm::VernalEquinox.in_year(year).unwrap().date;
+        let autumn = SolarTerm::AutumnEquinox.in_year(year).unwrap().date;
+        assert!(year < 1949 || jpholiday::is_holiday(vernal), "{vernal}");
+        assert!(jpholiday::is_holiday(autumn), "{autumn}");
+    }
+}
+
+#[test]
+fn names_and_longitudes() {
+    assert_eq!(
+        SolarTerm::from_name("啓蟄"),
+        Some(SolarTerm::AwakeningOfInsects)
+    );
+    assert_eq!(SolarTerm::from_name("春分の日"), None);
+    assert_eq!(SolarTerm::VernalEquinox.longitude(), 0);
+    assert_eq!(SolarTerm::WinterSolstice.longitude(), 270);
+    assert_eq!(SolarTerm::MinorCold.to_string(), "小寒");
+}
+
+#[test]
+fn supported_years() {
+    assert_eq!(SolarTerm::SUPPORTED_YEARS, 1873..=3000);
+    assert!(jpholiday::solar_terms(1873).is_some());
+    assert!(jpholiday::solar_terms(3000).is_some());
+    // グレゴリオ暦の採用前（ユリウス暦の時代を含む）や遠い将来は対象外。
+    assert_eq!(jpholiday::solar_terms(1872), None);
+    assert_eq!(SolarTerm::VernalEquinox.in_year(1500), None);
+    assert_eq!(SolarTerm::VernalEquinox.in_year(3001), None);
+    assert_eq!(SolarTerm::WinterSolstice.in_year(i32::MAX), None);
+    assert_eq!(SolarTerm::MinorCold.in_year(i32::MIN), None);
+}
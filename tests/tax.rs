@@ -0,0 +1,108 @@
+//! 国税の申告・納付期限を検証する。
+
+use jpholiday::{Date, TaxDeadline, TaxDeadlineKind};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn income_tax_return() {
+    let deadline = TaxDeadline::income_tax_return(2023).unwrap();
+    assert_eq!(deadline.statutory_date, d(2024, 3, 15));
+    assert_eq!(deadline.due_date, d(2024, 3, 15));
+    assert!(!deadline.is_extended());
+    // 2026-03-15 は日曜日。
+    let deadline = TaxDeadline::income_tax_return(2025).unwrap();
+    assert_eq!(deadline.due_date, d(2026, 3, 16));
+    assert!(deadline.is_extended());
+    assert_eq!(deadline.kind.to_string(), "所得税の確定申告（2025年分）");
+}
+
+#[test]
+fn withholding_tax() {
+    // 2024-02-10 (土)、2/11 (日・建国記念の日)、2/12 (振替休日) を経て 2/13。
+    let deadline = TaxDeadline::withholding_tax(2024, 1).unwrap();
+    assert_eq!(deadline.due_date, d(2024, 2, 13));
+    // 12 月分は翌年 1 月 10 日。
+    assert_eq!(
+        TaxDeadline::withholding_tax(2024, 12).unwrap().due_date,
+        d(2025, 1, 10)
+    );
+    assert!(TaxDeadline::withholding_tax(2024, 13).is_none());
+    assert!(TaxDeadline::withholding_tax(2024, 0).is_none());
+}
+
+#[test]
+fn corporate_tax_return() {
+    assert_eq!(
+        TaxDeadline::corporate_tax_return(d(2023, 12, 31))
+            .unwrap()
+            .due_date,
+        d(2024, 2, 29)
+    );
+    // 2024-11-30 は土曜日。
+    assert_eq!(
+        TaxDeadline::corporate_tax_return(d(2024, 9, 30))
+            .unwrap()
+            .due_date,
+        d(2024, 12, 2)
+    );
+    // 12/31 は年末年始の休日のため、1/4 (土)・1/5 (日) を経て 1/6。
+    let deadline = TaxDeadline::corporate_tax_return(d(2024, 10, 31)).unwrap();
+    assert_eq!(deadline.statutory_date, d(2024, 12, 31));
+    assert_eq!(deadline.due_date, d(2025, 1, 6));
+}
+
+#[test]
+fn deadlines_in_year() {
+    let all = TaxDeadline::deadlines_in(2024);
+    assert_eq!(all.len(), 13);
+    assert_eq!(
+        all[0].kind,
+        TaxDeadlineKind::WithholdingTax {
+            year: 2023,
+            month: 12
+        }
+    );
+    assert_eq!(all[0].due_date, d(2024, 1, 10));
+    assert_eq!(
+        all[3].kind,
+        TaxDeadlineKind::IncomeTaxReturn { tax_year: 2023 }
+    );
+    assert!(
+        all.windows(2)
+            .all(|w| w[0].statutory_date <= w[1].statutory_date)
+    );
+    assert!(all.iter().all(|t| t.statutory_date.year() == 2024));
+}
+
+#[test]
+fn corporate_tax_return_uses_corresponding_day() {
+    // 翌日 3/21 から 2 か月: 応当日 5/21 の前日。
+    let deadline = TaxDeadline::corporate_tax_return(d(2024, 3, 20)).unwrap();
+    assert_eq!(deadline.statutory_date, d(2024, 5, 20));
+    assert_eq!(deadline.due_date, d(2024, 5, 20));
+    assert_eq!(
+        TaxDeadline::corporate_tax_return(d(2024, 3, 31))
+            .unwrap()
+            .statutory_date,
+        d(2024, 5, 31)
+    );
+    // 翌日 12/30 から 2 か月: 応当日 2/30 がないため 2 月末日。
+    assert_eq!(
+        TaxDeadline::corporate_tax_return(d(2023, 12, 29))
+            .unwrap()
+            .statutory_date,
+        d(2024, 2, 29)
+    );
+}
+
+#[test]
+fn out_of_range_years() {
+    assert!(TaxDeadline::income_tax_return(i32::MAX).is_none());
+    assert!(TaxDeadline::withholding_tax(i32::MAX, 12).is_none());
+    assert!(TaxDeadline::corporate_tax_return(d(i32::MAX, 11, 30)).is_none());
+    assert_eq!(TaxDeadline::deadlines_in(i32::MIN).len(), 11);
+}
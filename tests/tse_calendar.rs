@@ -0,0 +1,62 @@
+//! 東京証券取引所の売買日カレンダーを検証する。
+
+use jpholiday::{Date, JPHoliday, TseCalendar};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn trading_days() {
+    let tse = TseCalendar::new();
+    assert!(tse.is_trading_day(d(2024, 1, 4))); // 大発会
+    assert!(!tse.is_trading_day(d(2023, 12, 31)));
+    assert!(!tse.is_trading_day(d(2024, 1, 2)));
+    assert!(!tse.is_trading_day(d(2024, 1, 8))); // 成人の日
+    assert!(!tse.is_trading_day(d(2024, 1, 6))); // 土曜日
+    assert_eq!(tse.previous_trading_day(d(2024, 1, 4)), d(2023, 12, 29));
+    assert_eq!(tse.next_trading_day(d(2024, 1, 5)), d(2024, 1, 9));
+}
+
+#[test]
+fn trading_halt_is_not_a_holiday() {
+    let tse = TseCalendar::new();
+    // 2020-10-01 はシステム障害で終日売買停止。休業日ではない。
+    assert!(TseCalendar::is_trading_halt(d(2020, 10, 1)));
+    assert!(!tse.is_trading_day(d(2020, 10, 1)));
+    assert!(!tse.calendar().is_holiday(d(2020, 10, 1)));
+    assert_eq!(tse.next_trading_day(d(2020, 9, 30)), d(2020, 10, 2));
+    assert_eq!(tse.previous_trading_day(d(2020, 10, 2)), d(2020, 9, 30));
+    assert_eq!(tse.add_trading_days(d(2020, 9, 30), 1), d(2020, 10, 2));
+    // 受渡しは行われたため、受渡日は遅れない。
+    assert_eq!(tse.settlement_date(d(2020, 9, 29)), d(2020, 10, 1));
+    assert_eq!(tse.settlement_date(d(2020, 9, 30)), d(2020, 10, 2));
+    assert!(!JPHoliday::new().is_holiday(d(2020, 10, 1)));
+}
+
+#[test]
+fn funeral_of_emperor_showa_is_a_national_holiday() {
+    let tse = TseCalendar::new();
+    // 1989-02-24（大喪の礼）は国民の祝日として休業。
+    assert!(!tse.is_trading_day(d(1989, 2, 24)));
+    assert_eq!(
+        tse.calendar().is_holiday_name(d(1989, 2, 24)).as_deref(),
+        Some("昭和天皇の大喪の礼")
+    );
+    assert_eq!(tse.next_trading_day(d(1989, 2, 23)), d(1989, 2, 27));
+}
+
+#[test]
+fn settlement_cycle_switch() {
+    let tse = TseCalendar::new();
+    assert_eq!(TseCalendar::settlement_cycle(d(2019, 7, 12)), 3);
+    assert_eq!(TseCalendar::settlement_cycle(d(2019, 7, 16)), 2);
+    // 7/12 (金) 約定の T+3 と 7/16 (火) 約定の T+2 はともに 7/18（7/15 は海の日）。
+    assert_eq!(tse.settlement_date(d(2019, 7, 12)), d(2019, 7, 18));
+    assert_eq!(tse.settlement_date(d(2019, 7, 16)), d(2019, 7, 18));
+    assert_eq!(tse.settlement_date(d(2019, 7, 17)), d(2019, 7, 19));
+    // 年末をまたぐ受渡し。
+    assert_eq!(tse.settlement_date(d(2023, 12, 28)), d(2024, 1, 4));
+    assert_eq!(tse.settlement_date_after(d(2023, 12, 29), 1), d(2024, 1, 4));
+}
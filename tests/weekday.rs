@@ -0,0 +1,82 @@
+//! [`Weekday`] と曜日を引数に取る API を検証する。
+
+use jpholiday::{Date, Weekday, Weekend};
+
+#[inline]
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::new(y, m, day).unwrap()
+}
+
+#[test]
+fn day_of_week_matches_numeric_apis() {
+    let mut date = d(2024, 1, 1);
+    while date < d(2024, 3, 1) {
+        let weekday = date.day_of_week();
+        assert_eq!(weekday.iso_number(), date.iso_weekday());
+        assert_eq!(weekday.number_from_monday(), date.weekday());
+        date = date.succ();
+    }
+    assert_eq!(d(2017, 1, 1).day_of_week(), Weekday::Sunday);
+    assert_eq!(d(2019, 5, 1).day_of_week(), Weekday::Wednesday);
+}
+
+#[test]
+fn conversions_and_names() {
+    for (i, weekday) in Weekday::iter().enumerate() {
+        assert_eq!(Weekday::from_iso(i as u32 + 1), Some(weekday));
+        assert_eq!(Weekday::from_number_from_monday(i as u32), Some(weekday));
+    }
+    assert_eq!(Weekday::from_iso(0), None);
+    assert_eq!(Weekday::from_iso(8), None);
+    assert_eq!(Weekday::from_number_from_monday(7), None);
+
+    assert_eq!(Weekday::Saturday.japanese_name(), "土曜日");
+    assert_eq!(Weekday::Saturday.japanese_short_name(), "土");
+    assert_eq!(Weekday::Saturday.english_name(), "Saturday");
+    assert_eq!(Weekday::Saturday.to_string(), "土曜日");
+}
+
+#[test]
+fn succ_and_pred_wrap_around() {
+    assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+    assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+    for weekday in Weekday::ALL {
+        assert_eq!(weekday.succ().pred(), weekday);
+    }
+    assert_eq!(Weekday::iter().next_back(), Some(Weekday::Sunday));
+    assert_eq!(Weekday::iter().len(), 7);
+}
+
+#[test]
+fn nth_weekday() {
+    // 2024 年の海の日（7 月第 3 月曜日）・敬老の日（9 月第 3 月曜日）。
+    assert_eq!(
+        Date::nth_weekday(2024, 7, 3, Weekday::Monday),
+        Some(d(2024, 7, 15))
+    );
+    assert_eq!(
+        Date::nth_weekday(2024, 9, 3, Weekday::Monday),
+        Some(d(2024, 9, 16))
+    );
+    // 月初が対象の曜日の場合。
+    assert_eq!(
+        Date::nth_weekday(2024, 1, 1, Weekday::Monday),
+        Some(d(2024, 1, 1))
+    );
+    assert_eq!(
+        Date::nth_weekday(2024, 2, 5, Weekday::Thursday),
+        Some(d(2024, 2, 29))
+    );
+    assert_eq!(Date::nth_weekday(2024, 2, 5, Weekday::Friday), None);
+    assert_eq!(Date::nth_weekday(2024, 2, 0, Weekday::Friday), None);
+    assert_eq!(Date::nth_weekday(2024, 13, 1, Weekday::Friday), None);
+}
+
+#[test]
+fn weekend_from_weekdays() {
+    let weekend = Weekend::from_weekdays(&[Weekday::Saturday, Weekday::Sunday]).unwrap();
+    assert_eq!(weekend, Weekend::SATURDAY_SUNDAY);
+    assert!(weekend.contains_weekday(Weekday::Sunday));
+    assert!(!weekend.contains_weekday(Weekday::Friday));
+    assert_eq!(Weekend::from_weekdays(&Weekday::ALL), None);
+}